@@ -0,0 +1,282 @@
+//! Long-term goals across runs. Definitions live in `assets/data/achievements.ron`, progress is
+//! persisted in the `PkvStore` and a toast pops up when an achievement is unlocked.
+use crate::assets::audio_assets::Fonts;
+use crate::components::spawnable::{MobDestroyedEvent, MobType};
+use crate::gameplay::gamelogic::PlayerLevel;
+use crate::gameplay::upgrade::{PlayerUpgrades, UpgradeEvent};
+use crate::gameplay::GameStates;
+use crate::screens::AppStates;
+use crate::theme::language::{Localize, LocalizeFont};
+use crate::util::Colour;
+use bevy::asset::ron::de::from_bytes;
+use bevy::prelude::*;
+use bevy_pkv::PkvStore;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const ACHIEVEMENTS_KEY: &str = "achievements";
+
+/// How long an unlock toast stays on screen
+const TOAST_SECONDS: f32 = 4.0;
+
+/// What has to happen to unlock an achievement
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub enum AchievementCondition {
+    /// Enemies destroyed over all runs
+    Kills(u32),
+    /// Enemies of one kind destroyed over all runs
+    KillsOf(MobType, u32),
+    /// Player level reached in a single run
+    Level(u32),
+    DefeatFinalBoss,
+    /// Any weapon upgraded to `PlayerUpgrades::max_allowed_level`
+    MaxWeapon,
+}
+
+impl AchievementCondition {
+    /// Progress needed to unlock
+    pub fn target(&self) -> u32 {
+        match self {
+            AchievementCondition::Kills(count) => *count,
+            AchievementCondition::KillsOf(_, count) => *count,
+            AchievementCondition::Level(level) => *level,
+            AchievementCondition::DefeatFinalBoss => 1,
+            AchievementCondition::MaxWeapon => PlayerUpgrades::max_allowed_level() as u32,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Achievement {
+    /// Also names the translations, `achievement.{id}` and `achievement.{id}.desc`
+    pub id: String,
+    pub condition: AchievementCondition,
+}
+
+impl Achievement {
+    pub fn name_key(&self) -> String {
+        format!("achievement.{}", self.id)
+    }
+
+    pub fn description_key(&self) -> String {
+        format!("achievement.{}.desc", self.id)
+    }
+}
+
+/// Every achievement, in the order they are listed
+#[derive(Resource, Deserialize, Debug)]
+pub struct AchievementsResource {
+    pub achievements: Vec<Achievement>,
+}
+
+/// Progress towards each achievement by id, persisted between runs
+#[derive(Resource, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AchievementProgress {
+    pub progress: HashMap<String, u32>,
+    /// Ids of the unlocked achievements, in unlock order
+    pub unlocked: Vec<String>,
+}
+
+impl AchievementProgress {
+    pub fn is_unlocked(&self, id: &str) -> bool {
+        self.unlocked.iter().any(|unlocked| unlocked == id)
+    }
+
+    pub fn get(&self, id: &str) -> u32 {
+        self.progress.get(id).copied().unwrap_or(0)
+    }
+
+    /// Applies `measure` to the progress of every locked achievement and returns the ones it
+    /// unlocked. `measure` gets the condition and the current progress and returns the new one.
+    pub fn update(
+        &mut self,
+        achievements: &AchievementsResource,
+        measure: impl Fn(&AchievementCondition, u32) -> u32,
+    ) -> Vec<Achievement> {
+        let mut unlocked = vec![];
+        for achievement in &achievements.achievements {
+            if self.is_unlocked(&achievement.id) {
+                continue;
+            }
+            let current = self.get(&achievement.id);
+            let target = achievement.condition.target();
+            let progress = measure(&achievement.condition, current).min(target);
+            if progress == current {
+                continue;
+            }
+            self.progress.insert(achievement.id.clone(), progress);
+            if progress >= target {
+                self.unlocked.push(achievement.id.clone());
+                unlocked.push(achievement.clone());
+            }
+        }
+        unlocked
+    }
+}
+
+/// Sent once when an achievement is unlocked
+#[derive(Event)]
+pub struct AchievementUnlockedEvent(pub Achievement);
+
+/// Unlock notification, despawned when the timer runs out
+#[derive(Component)]
+struct Toast(Timer);
+
+pub(super) fn plugin(app: &mut App) {
+    app.insert_resource(
+        from_bytes::<AchievementsResource>(include_bytes!("../assets/data/achievements.ron"))
+            .unwrap(),
+    )
+    .init_resource::<AchievementProgress>()
+    .add_event::<AchievementUnlockedEvent>()
+    .add_systems(Startup, load_progress)
+    .add_systems(
+        Update,
+        (
+            track_kills,
+            track_level.run_if(resource_exists_and_changed::<PlayerLevel>),
+            track_weapons.run_if(resource_changed::<PlayerUpgrades>),
+        )
+            .distributive_run_if(in_state(AppStates::Game)),
+    )
+    .add_systems(
+        Update,
+        save_progress.run_if(on_event::<AchievementUnlockedEvent>),
+    )
+    .add_systems(OnEnter(GameStates::GameOver), save_progress)
+    .add_systems(OnExit(AppStates::Game), save_progress)
+    .add_systems(Update, (spawn_toasts, update_toasts));
+}
+
+fn load_progress(pkv: Res<PkvStore>, mut progress: ResMut<AchievementProgress>) {
+    if let Ok(v) = pkv.get::<String>(ACHIEVEMENTS_KEY) {
+        if let Ok(deserialized) = serde_json::from_str(v.as_str()) {
+            *progress = deserialized;
+        }
+    }
+}
+
+/// Saves on unlocks and at the end of a run, not on every kill
+fn save_progress(mut pkv: ResMut<PkvStore>, progress: Res<AchievementProgress>) {
+    if let Ok(serialized) = serde_json::to_string(&progress.into_inner()) {
+        if let Err(err) = pkv.set::<String>(ACHIEVEMENTS_KEY, &serialized) {
+            warn!("Failed to save achievements: {}", err);
+        }
+    } else {
+        warn!("Failed to serialize achievements");
+    }
+}
+
+fn track_kills(
+    mut mob_destroyed_events: EventReader<MobDestroyedEvent>,
+    achievements: Res<AchievementsResource>,
+    mut progress: ResMut<AchievementProgress>,
+    mut unlocked_event: EventWriter<AchievementUnlockedEvent>,
+) {
+    for ev in mob_destroyed_events.read() {
+        if !matches!(ev.mob_type, MobType::Enemy(_)) {
+            continue;
+        }
+        let unlocked = progress.update(&achievements, |condition, current| match condition {
+            AchievementCondition::Kills(_) => current + 1,
+            AchievementCondition::KillsOf(mob_type, _) if *mob_type == ev.mob_type => current + 1,
+            AchievementCondition::DefeatFinalBoss if ev.is_boss => 1,
+            _ => current,
+        });
+        unlocked_event.send_batch(unlocked.into_iter().map(AchievementUnlockedEvent));
+    }
+}
+
+fn track_level(
+    level: Res<PlayerLevel>,
+    achievements: Res<AchievementsResource>,
+    mut progress: ResMut<AchievementProgress>,
+    mut unlocked_event: EventWriter<AchievementUnlockedEvent>,
+) {
+    let unlocked = progress.update(&achievements, |condition, current| match condition {
+        AchievementCondition::Level(_) => current.max(level.value as u32),
+        _ => current,
+    });
+    unlocked_event.send_batch(unlocked.into_iter().map(AchievementUnlockedEvent));
+}
+
+fn track_weapons(
+    upgrades: Res<PlayerUpgrades>,
+    achievements: Res<AchievementsResource>,
+    mut progress: ResMut<AchievementProgress>,
+    mut unlocked_event: EventWriter<AchievementUnlockedEvent>,
+) {
+    let best_weapon = upgrades
+        .0
+        .iter()
+        .filter(|(upgrade, _)| matches!(upgrade, UpgradeEvent::Weapon(_)))
+        .map(|(_, level)| *level as u32)
+        .max()
+        .unwrap_or(0);
+    let unlocked = progress.update(&achievements, |condition, current| match condition {
+        AchievementCondition::MaxWeapon => current.max(best_weapon),
+        _ => current,
+    });
+    unlocked_event.send_batch(unlocked.into_iter().map(AchievementUnlockedEvent));
+}
+
+fn spawn_toasts(
+    mut commands: Commands,
+    mut unlocked_events: EventReader<AchievementUnlockedEvent>,
+    fonts: Res<Fonts>,
+    localize: Res<Localize>,
+    toasts: Query<(), With<Toast>>,
+) {
+    let mut shown = toasts.iter().count();
+    for ev in unlocked_events.read() {
+        commands
+            .spawn((
+                Name::new("Achievement Toast"),
+                Toast(Timer::from_seconds(TOAST_SECONDS, TimerMode::Once)),
+                Node {
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(20.0),
+                    top: Val::Px(20.0 + shown as f32 * 70.0),
+                    padding: UiRect::all(Val::Px(10.0)),
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                },
+                BackgroundColor(Colour::BLACK.with_alpha(0.8)),
+                GlobalZIndex(10),
+            ))
+            .with_children(|toast| {
+                toast.spawn((
+                    Text::new(localize.format(
+                        "achievement.unlocked",
+                        &[("name", &localize.get(&ev.0.name_key()))],
+                    )),
+                    TextFont {
+                        font: fonts.primary.clone(),
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Colour::YELLOW),
+                    LocalizeFont,
+                ));
+                toast.spawn((
+                    Text::new(localize.get(&ev.0.description_key())),
+                    TextFont {
+                        font: fonts.primary.clone(),
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(Colour::WHITE),
+                    LocalizeFont,
+                ));
+            });
+        shown += 1;
+    }
+}
+
+fn update_toasts(mut commands: Commands, time: Res<Time>, mut toasts: Query<(Entity, &mut Toast)>) {
+    for (entity, mut toast) in &mut toasts {
+        if toast.0.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
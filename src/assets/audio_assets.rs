@@ -129,6 +129,26 @@ pub struct GameAudioAssets {
     pub button_release_3: Handle<AudioSource>,
     #[asset(key = "sounds.button_confirm")]
     pub button_confirm: Handle<AudioSource>,
+    #[asset(key = "sounds.button_hover")]
+    pub button_hover: Handle<AudioSource>,
+    #[asset(key = "sounds.button_press")]
+    pub button_press: Handle<AudioSource>,
+    #[asset(key = "sounds.bullet_hit_player")]
+    pub bullet_hit_player: Handle<AudioSource>,
+    #[asset(key = "sounds.bullet_hit_mob")]
+    pub bullet_hit_mob: Handle<AudioSource>,
+    #[asset(key = "sounds.laser")]
+    pub laser: Handle<AudioSource>,
+    #[asset(key = "sounds.shield_up")]
+    pub shield_up: Handle<AudioSource>,
+    #[asset(key = "sounds.big_explosion")]
+    pub big_explosion: Handle<AudioSource>,
+    #[asset(key = "sounds.mine_launch")]
+    pub mine_launch: Handle<AudioSource>,
+    #[asset(key = "sounds.rocket_launch")]
+    pub rocket_launch: Handle<AudioSource>,
+    #[asset(key = "sounds.game_over")]
+    pub game_over: Handle<AudioSource>,
 }
 
 impl GameAudioAssets {
@@ -184,6 +204,18 @@ impl GameAudioAssets {
                 }
             }
             SoundEffectType::ButtonConfirm => self.button_confirm.clone(),
+            SoundEffectType::ButtonHover => self.button_hover.clone(),
+            SoundEffectType::ButtonPress => self.button_press.clone(),
+            SoundEffectType::BulletHitPlayer => self.bullet_hit_player.clone(),
+            SoundEffectType::BulletHitMob | SoundEffectType::BulletHitBoss => {
+                self.bullet_hit_mob.clone()
+            }
+            SoundEffectType::Laser => self.laser.clone(),
+            SoundEffectType::ShieldUp => self.shield_up.clone(),
+            SoundEffectType::BigExplosion => self.big_explosion.clone(),
+            SoundEffectType::MineLaunch => self.mine_launch.clone(),
+            SoundEffectType::RocketLaunch => self.rocket_launch.clone(),
+            SoundEffectType::GameOver => self.game_over.clone(),
         }
     }
 }
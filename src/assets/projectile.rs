@@ -1,7 +1,7 @@
 use crate::components::spawnable::{Faction, ProjectileType};
 use bevy::{
     asset::Handle,
-    prelude::{Image, Res, Resource},
+    prelude::{Image, Res, Resource, Sprite, TextureAtlas},
     sprite::TextureAtlasLayout,
 };
 use bevy_asset_loader::asset_collection::AssetCollection;
@@ -29,41 +29,73 @@ pub struct ProjectileAssets {
     pub enemy_bullet_layout: Handle<TextureAtlasLayout>,
     #[asset(key = "enemy_bullet.image")]
     pub enemy_bullet_image: Handle<Image>,
+    #[asset(key = "neutral_bullet.layout")]
+    pub neutral_bullet_layout: Handle<TextureAtlasLayout>,
+    #[asset(key = "neutral_bullet.image")]
+    pub neutral_bullet_image: Handle<Image>,
+    #[asset(key = "ally_beam.layout")]
+    pub ally_beam_layout: Handle<TextureAtlasLayout>,
+    #[asset(key = "ally_beam.image")]
+    pub ally_beam_image: Handle<Image>,
+    #[asset(key = "enemy_beam.layout")]
+    pub enemy_beam_layout: Handle<TextureAtlasLayout>,
+    #[asset(key = "enemy_beam.image")]
+    pub enemy_beam_image: Handle<Image>,
 }
 
 impl ProjectileAssets {
     /// Use a ProjectileType enum to access a texture atlas layout
+    /// Option because not every projectile has sprite art (there is no neutral beam)
     pub fn get_texture_atlas_layout(
         &self,
         projectile_type: &ProjectileType,
-    ) -> Handle<TextureAtlasLayout> {
+    ) -> Option<Handle<TextureAtlasLayout>> {
         match projectile_type {
             ProjectileType::Blast(faction) => match faction {
-                Faction::Ally => self.ally_blast_layout.clone(),
-                Faction::Enemy => self.enemy_blast_layout.clone(),
-                Faction::Neutral => self.neutral_blast_layout.clone(),
+                Faction::Ally => Some(self.ally_blast_layout.clone()),
+                Faction::Enemy => Some(self.enemy_blast_layout.clone()),
+                Faction::Neutral => Some(self.neutral_blast_layout.clone()),
             },
             ProjectileType::Bullet(faction) => match faction {
-                Faction::Ally => self.ally_bullet_layout.clone(),
-                Faction::Enemy => self.enemy_bullet_layout.clone(),
-                Faction::Neutral => todo!(),
+                Faction::Ally => Some(self.ally_bullet_layout.clone()),
+                Faction::Enemy => Some(self.enemy_bullet_layout.clone()),
+                Faction::Neutral => Some(self.neutral_bullet_layout.clone()),
+            },
+            ProjectileType::Beam(faction) => match faction {
+                Faction::Ally => Some(self.ally_beam_layout.clone()),
+                Faction::Enemy => Some(self.enemy_beam_layout.clone()),
+                Faction::Neutral => None,
             },
         }
     }
 
     /// Use a ProjectileType enum to access an image handle
-    pub fn get_image(&self, projectile_type: &ProjectileType) -> Handle<Image> {
+    /// Option because not every projectile has sprite art (there is no neutral beam)
+    pub fn get_image(&self, projectile_type: &ProjectileType) -> Option<Handle<Image>> {
         match projectile_type {
             ProjectileType::Blast(faction) => match faction {
-                Faction::Ally => self.ally_blast_image.clone(),
-                Faction::Enemy => self.enemy_blast_image.clone(),
-                Faction::Neutral => self.neutral_blast_image.clone(),
+                Faction::Ally => Some(self.ally_blast_image.clone()),
+                Faction::Enemy => Some(self.enemy_blast_image.clone()),
+                Faction::Neutral => Some(self.neutral_blast_image.clone()),
             },
             ProjectileType::Bullet(faction) => match faction {
-                Faction::Ally => self.ally_bullet_image.clone(),
-                Faction::Enemy => self.enemy_bullet_image.clone(),
-                Faction::Neutral => todo!(),
+                Faction::Ally => Some(self.ally_bullet_image.clone()),
+                Faction::Enemy => Some(self.enemy_bullet_image.clone()),
+                Faction::Neutral => Some(self.neutral_bullet_image.clone()),
+            },
+            ProjectileType::Beam(faction) => match faction {
+                Faction::Ally => Some(self.ally_beam_image.clone()),
+                Faction::Enemy => Some(self.enemy_beam_image.clone()),
+                Faction::Neutral => None,
             },
         }
     }
+
+    /// Builds a sprite for a ProjectileType, None if the projectile has no sprite art
+    pub fn get_sprite(&self, projectile_type: &ProjectileType) -> Option<Sprite> {
+        Some(Sprite::from_atlas_image(
+            self.get_image(projectile_type)?,
+            TextureAtlas::from(self.get_texture_atlas_layout(projectile_type)?),
+        ))
+    }
 }
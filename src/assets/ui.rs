@@ -55,6 +55,8 @@ pub struct UiAssets {
     pub warning_gradient: Handle<Image>,
     #[asset(key = "defense_gradient")]
     pub defense_gradient: Handle<Image>,
+    #[asset(key = "victory_background")]
+    pub victory_background: Handle<Image>,
     #[asset(key = "stat_icon.damage")]
     pub damage_icon: Handle<Image>,
     #[asset(key = "stat_icon.speed")]
@@ -1,8 +1,20 @@
 //! Exposes a plugin that starts, stops, and modulates in-game audio when events are emitted
 use crate::assets::audio_assets::GameAudioAssets;
-use crate::components::audio::{ChangeBackgroundMusicEvent, PlaySoundEffectEvent};
+use crate::components::audio::{
+    BGMusicType, ChangeBackgroundMusicEvent, PlaySoundEffectEvent, SfxPriority, SoundEffectType,
+};
+use crate::components::spawnable::MobComponent;
+use crate::config::GameConfig;
+use crate::enemy::director::DirectorTable;
+use crate::enemy::FinalBoss;
+use crate::gameplay::GameStates;
+use crate::screens::AppStates;
+use crate::MainCamera;
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
 use bevy_kira_audio::prelude::*;
+use std::time::Duration;
 
 /// Starts, stops, and modulates in-game audio when we receive a `PlaySoundEffectEvent` or `ChangeBackgroundMusicEvent`.
 pub(super) fn plugin(app: &mut App) {
@@ -13,13 +25,25 @@ pub(super) fn plugin(app: &mut App) {
         .add_audio_channel::<MenuAudioChannel>()
         .add_audio_channel::<SoundEffectsAudioChannel>();
 
+    app.init_resource::<SoundEffectMixer>()
+        .init_resource::<MusicDirector>();
+
     app.add_systems(Startup, set_audio_volume_system);
 
     app.add_systems(
         Update,
-        (play_sound_effect_system, change_bg_music_system)
-            .run_if(resource_exists::<GameAudioAssets>),
+        (
+            play_sound_effect_system,
+            music_director_system,
+            change_bg_music_system,
+        )
+            .chain()
+            .distributive_run_if(resource_exists::<GameAudioAssets>),
     );
+    app.add_systems(Update, music_volume_system);
+
+    // Mix once everything had the chance to request sounds this frame
+    app.add_systems(PostUpdate, mix_sound_effects_system);
 }
 
 // audio channels
@@ -30,13 +54,87 @@ pub struct MenuAudioChannel;
 #[derive(Resource)]
 pub struct SoundEffectsAudioChannel;
 
+/// Distance from the camera, in half screens, at which positional sounds are no longer played
+const SPATIAL_CULL_DISTANCE: f32 = 2.0;
+
+/// Sound effects that may play at the same time before anything but high priority is dropped
+pub const MAX_SFX_VOICES: usize = 32;
+
+/// A sound effect waiting for the mixer at the end of the frame
+pub struct SfxRequest {
+    pub sound_effect_type: SoundEffectType,
+    pub source: Handle<AudioSource>,
+    pub volume: f64,
+    pub panning: f64,
+}
+
+/// Collects the sound effects requested during a frame and plays them on the
+/// [`SoundEffectsAudioChannel`], collapsing duplicates and capping the voices of each sound
+#[derive(Resource, Default)]
+pub struct SoundEffectMixer {
+    requests: Vec<SfxRequest>,
+    voices: HashMap<SoundEffectType, Vec<Handle<AudioInstance>>>,
+}
+
+/// Plays sound effects at a world position, panned and attenuated relative to the [`MainCamera`]
+#[derive(SystemParam)]
+pub struct SpatialAudio<'w, 's> {
+    mixer: ResMut<'w, SoundEffectMixer>,
+    audio_assets: Res<'w, GameAudioAssets>,
+    config: Res<'w, GameConfig>,
+    camera: Query<
+        'w,
+        's,
+        (&'static GlobalTransform, &'static OrthographicProjection),
+        With<MainCamera>,
+    >,
+}
+
+impl SpatialAudio<'_, '_> {
+    /// Plays a sound as heard from the camera, sounds far off-screen are skipped
+    pub fn play_at(&mut self, sound_effect_type: SoundEffectType, position: Vec2) {
+        let Some((attenuation, panning)) = self.spatialize(position) else {
+            return;
+        };
+        self.request(sound_effect_type, attenuation, panning);
+    }
+
+    /// Plays a sound that doesn't come from anywhere in the world, centred at full volume
+    pub fn play(&mut self, sound_effect_type: SoundEffectType) {
+        self.request(sound_effect_type, 1.0, 0.5);
+    }
+
+    fn request(&mut self, sound_effect_type: SoundEffectType, attenuation: f64, panning: f64) {
+        self.mixer.requests.push(SfxRequest {
+            sound_effect_type,
+            source: self.audio_assets.get_sound_effect(&sound_effect_type),
+            volume: attenuation * self.config.sfx_volume as f64,
+            panning,
+        });
+    }
+
+    /// Volume multiplier and panning (0 left, 1 right) of a sound at `position`, None if culled
+    fn spatialize(&self, position: Vec2) -> Option<(f64, f64)> {
+        let Ok((camera, projection)) = self.camera.get_single() else {
+            return Some((1.0, 0.5));
+        };
+        // Offset measured in half screens, so the screen edges are at 1
+        let offset = (position - camera.translation().truncate()) / projection.area.half_size();
+        let distance = offset.abs().max_element();
+        if distance >= SPATIAL_CULL_DISTANCE {
+            return None;
+        }
+        let attenuation = 1.0 - (distance / SPATIAL_CULL_DISTANCE).powi(2);
+        let panning = 0.5 + offset.x.clamp(-1.0, 1.0) * 0.3;
+        Some((attenuation as f64, panning as f64))
+    }
+}
+
 /// Sets the volume of the audio channels to "sane defaults"
 fn set_audio_volume_system(
-    background_audio_channel: Res<AudioChannel<BackgroundMusicAudioChannel>>,
     menu_audio_channel: Res<AudioChannel<MenuAudioChannel>>,
     effects_audio_channel: Res<AudioChannel<SoundEffectsAudioChannel>>,
 ) {
-    background_audio_channel.set_volume(0.20);
     menu_audio_channel.set_volume(0.05);
     effects_audio_channel.set_volume(0.80);
 }
@@ -44,12 +142,215 @@ fn set_audio_volume_system(
 /// Play sound effects when we receive events. This should be called every frame for snappy audio.
 fn play_sound_effect_system(
     mut play_sound_event_reader: EventReader<PlaySoundEffectEvent>,
+    mut audio: SpatialAudio,
+) {
+    for event in play_sound_event_reader.read() {
+        match event.position {
+            Some(position) => audio.play_at(event.sound_effect_type, position),
+            None => audio.play(event.sound_effect_type),
+        }
+    }
+}
+
+/// Picks the sound effects to play out of the requests of a frame, given how many voices of each
+/// sound are already `playing`. Identical sounds are collapsed into the loudest request, and
+/// sounds over their voice cap are dropped unless they are high priority, in which case they cut
+/// off the oldest voice of the same sound. Returns the sounds most important first, flagged when
+/// they cut off a voice, and updates `playing`.
+pub fn mix(
+    mut requests: Vec<SfxRequest>,
+    playing: &mut HashMap<SoundEffectType, usize>,
+) -> Vec<(SfxRequest, bool)> {
+    let mut total_voices: usize = playing.values().sum();
+
+    // Most important first, then loudest
+    requests.sort_by(|a, b| {
+        b.sound_effect_type
+            .priority()
+            .cmp(&a.sound_effect_type.priority())
+            .then(b.volume.total_cmp(&a.volume))
+    });
+
+    let mut played = HashSet::new();
+    let mut mixed = Vec::new();
+    for request in requests {
+        let sound_effect_type = request.sound_effect_type;
+        if !played.insert(sound_effect_type) {
+            continue;
+        }
+
+        let max_voices = sound_effect_type.max_voices();
+        let voices = playing.entry(sound_effect_type).or_default();
+        let cut_oldest = *voices >= max_voices;
+        if (cut_oldest || total_voices >= MAX_SFX_VOICES)
+            && sound_effect_type.priority() != SfxPriority::High
+        {
+            continue;
+        }
+        if !cut_oldest {
+            *voices += 1;
+            total_voices += 1;
+        }
+        mixed.push((request, cut_oldest));
+    }
+    mixed
+}
+
+/// Plays the sound effects requested this frame, see [`mix`]
+fn mix_sound_effects_system(
+    mut mixer: ResMut<SoundEffectMixer>,
     audio_channel: Res<AudioChannel<SoundEffectsAudioChannel>>,
+    mut audio_instances: ResMut<Assets<AudioInstance>>,
+) {
+    let mixer = &mut *mixer;
+
+    // Forget voices that finished playing
+    for voices in mixer.voices.values_mut() {
+        voices.retain(|voice| !matches!(audio_channel.state(voice), PlaybackState::Stopped));
+    }
+    mixer.voices.retain(|_, voices| !voices.is_empty());
+
+    let mut playing = mixer
+        .voices
+        .iter()
+        .map(|(sound_effect_type, voices)| (*sound_effect_type, voices.len()))
+        .collect();
+    let requests = std::mem::take(&mut mixer.requests);
+    for (request, cut_oldest) in mix(requests, &mut playing) {
+        let voices = mixer.voices.entry(request.sound_effect_type).or_default();
+        if cut_oldest {
+            let oldest = voices.remove(0);
+            if let Some(instance) = audio_instances.get_mut(&oldest) {
+                instance.stop(AudioTween::default());
+            }
+        }
+
+        let voice = audio_channel
+            .play(request.source)
+            .with_volume(Volume::Amplitude(request.volume))
+            .with_panning(request.panning)
+            .handle();
+        voices.push(voice);
+    }
+}
+
+/// How long tracks take to crossfade into each other
+const MUSIC_CROSSFADE: Duration = Duration::from_secs(2);
+
+/// Music volume multiplier while the game is paused or picking upgrades
+const MUSIC_DUCKING: f32 = 0.35;
+
+/// Picks the background music for the current screen, game state and boss fights
+#[derive(Resource, Default)]
+pub struct MusicDirector {
+    /// Track that is playing or fading in
+    playing: Option<BGMusicType>,
+    /// Counts down the boss transition before the boss theme starts looping
+    transition: Option<Timer>,
+}
+
+fn desired_music(
+    app_state: &AppStates,
+    game_state: &GameStates,
+    boss_fight: bool,
+) -> Option<BGMusicType> {
+    match app_state {
+        AppStates::MainMenu
+        | AppStates::HighScores
+        | AppStates::Achievements
+        | AppStates::Hangar
+        | AppStates::NewRun => Some(BGMusicType::Main),
+        AppStates::Credits => Some(BGMusicType::Game),
+        AppStates::Game => match game_state {
+            GameStates::GameOver => None,
+            GameStates::Victory => Some(BGMusicType::Main),
+            _ if boss_fight => Some(BGMusicType::Boss),
+            _ => Some(BGMusicType::Game),
+        },
+        _ => None,
+    }
+}
+
+/// Crossfades to the track that fits the game right now. Boss fights starting during the game
+/// play the boss transition once before the boss theme loops.
+fn music_director_system(
+    time: Res<Time>,
+    mut director: ResMut<MusicDirector>,
+    app_state: Res<State<AppStates>>,
+    game_state: Res<State<GameStates>>,
+    table: Res<DirectorTable>,
+    final_bosses: Query<(), With<FinalBoss>>,
+    mobs: Query<&MobComponent>,
     audio_assets: Res<GameAudioAssets>,
+    audio_sources: Res<Assets<AudioSource>>,
+    mut change_bg_music_event_writer: EventWriter<ChangeBackgroundMusicEvent>,
 ) {
-    for event in play_sound_event_reader.read() {
-        audio_channel.play(audio_assets.get_sound_effect(&event.sound_effect_type));
+    let boss_fight =
+        !final_bosses.is_empty() || mobs.iter().any(|mob| table.is_boss(&mob.mob_type));
+    let desired = desired_music(app_state.get(), game_state.get(), boss_fight);
+
+    if let Some(transition) = &mut director.transition {
+        transition.tick(time.delta());
+        if !transition.finished() && desired == Some(BGMusicType::Boss) {
+            return;
+        }
+        director.transition = None;
+        if desired == Some(BGMusicType::Boss) {
+            // The transition already faded out, the boss theme picks up where it ends
+            change_bg_music_event_writer.send(ChangeBackgroundMusicEvent {
+                bg_music_type: Some(BGMusicType::Boss),
+                loop_from: Some(0.0),
+                ..default()
+            });
+            director.playing = desired;
+            return;
+        }
+    }
+
+    if desired == director.playing {
+        return;
+    }
+
+    if desired == Some(BGMusicType::Boss) && director.playing == Some(BGMusicType::Game) {
+        let transition = audio_sources
+            .get(&audio_assets.get_bg_music_asset(&BGMusicType::BossTransition))
+            .map(|source| source.sound.duration())
+            .unwrap_or_default();
+        change_bg_music_event_writer.send(ChangeBackgroundMusicEvent {
+            bg_music_type: Some(BGMusicType::BossTransition),
+            fade_out: Some(MUSIC_CROSSFADE),
+            ..default()
+        });
+        director.transition = Some(Timer::new(transition, TimerMode::Once));
+        director.playing = Some(BGMusicType::BossTransition);
+        return;
+    }
+
+    change_bg_music_event_writer.send(ChangeBackgroundMusicEvent {
+        bg_music_type: desired.clone(),
+        loop_from: Some(0.0),
+        fade_in: Some(MUSIC_CROSSFADE),
+        fade_out: Some(MUSIC_CROSSFADE),
+    });
+    director.playing = desired;
+}
+
+/// Follows `GameConfig.bgm_volume`, ducking the music while the game is paused or in selection
+fn music_volume_system(
+    config: Res<GameConfig>,
+    game_state: Res<State<GameStates>>,
+    audio_channel: Res<AudioChannel<BackgroundMusicAudioChannel>>,
+) {
+    if !config.is_changed() && !game_state.is_changed() {
+        return;
     }
+    let ducking = match game_state.get() {
+        GameStates::Paused | GameStates::Selection => MUSIC_DUCKING,
+        _ => 1.0,
+    };
+    audio_channel
+        .set_volume((config.bgm_volume * ducking) as f64)
+        .fade_in(AudioTween::linear(Duration::from_millis(300)));
 }
 
 /// System to handle background music changes based on events.
@@ -1,4 +1,4 @@
-use bevy::prelude::Event;
+use bevy::prelude::{Event, Vec2};
 use serde::Deserialize;
 use std::time::Duration;
 use strum_macros::Display;
@@ -33,6 +33,69 @@ pub enum SoundEffectType {
     ButtonSelect,
     ButtonRelease,
     ButtonConfirm,
+    ButtonHover,
+    ButtonPress,
+    BulletHitPlayer,
+    BulletHitMob,
+    BulletHitBoss,
+    Laser,
+    ShieldUp,
+    BigExplosion,
+    MineLaunch,
+    RocketLaunch,
+    GameOver,
+}
+
+impl SoundEffectType {
+    /// How important the sound is when the mixer has to drop voices
+    pub fn priority(&self) -> SfxPriority {
+        match self {
+            SoundEffectType::PlayerExplosion
+            | SoundEffectType::PlayerHit
+            | SoundEffectType::BulletHitPlayer
+            | SoundEffectType::BulletHitBoss
+            | SoundEffectType::GameOver
+            | SoundEffectType::ObjectiveCompleted => SfxPriority::High,
+            SoundEffectType::BulletDing
+            | SoundEffectType::BulletBounce
+            | SoundEffectType::Laser
+            | SoundEffectType::Collision(_) => SfxPriority::Low,
+            _ => SfxPriority::Normal,
+        }
+    }
+
+    /// How many copies of the sound may play at the same time
+    pub fn max_voices(&self) -> usize {
+        match self {
+            SoundEffectType::MobHit
+            | SoundEffectType::BulletHitMob
+            | SoundEffectType::BulletDing
+            | SoundEffectType::BulletBounce
+            | SoundEffectType::Collision(_) => 3,
+            SoundEffectType::ButtonSelect
+            | SoundEffectType::ButtonRelease
+            | SoundEffectType::ButtonConfirm
+            | SoundEffectType::ButtonHover
+            | SoundEffectType::ButtonPress
+            | SoundEffectType::MenuInputSuccess
+            | SoundEffectType::GameOver => 1,
+            SoundEffectType::Laser => 2,
+            _ => DEFAULT_MAX_VOICES,
+        }
+    }
+}
+
+/// How many copies of a sound may play at the same time unless it says otherwise
+const DEFAULT_MAX_VOICES: usize = 4;
+
+/// How important a sound effect is, when there are too many voices the least important are dropped
+/// and high priority sounds are never dropped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum SfxPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
 }
 
 /// Subtype of sound effect for collisions
@@ -58,4 +121,22 @@ pub struct ChangeBackgroundMusicEvent {
 #[derive(Event)]
 pub struct PlaySoundEffectEvent {
     pub sound_effect_type: SoundEffectType,
+    /// World position the sound comes from, None plays it centred at full volume
+    pub position: Option<Vec2>,
+}
+
+impl PlaySoundEffectEvent {
+    pub fn new(sound_effect_type: SoundEffectType) -> Self {
+        Self {
+            sound_effect_type,
+            position: None,
+        }
+    }
+
+    pub fn at(sound_effect_type: SoundEffectType, position: Vec2) -> Self {
+        Self {
+            sound_effect_type,
+            position: Some(position),
+        }
+    }
 }
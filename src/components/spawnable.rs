@@ -1,3 +1,4 @@
+use crate::theme::language::LocalizeKey;
 use bevy::prelude::{Component, Entity, Event, Quat, Vec2};
 use serde::Deserialize;
 use std::default::Default;
@@ -43,6 +44,7 @@ impl Default for SpawnableType {
 pub enum ProjectileType {
     Blast(Faction),
     Bullet(Faction),
+    Beam(Faction),
 }
 
 impl ProjectileType {
@@ -50,6 +52,7 @@ impl ProjectileType {
         match self {
             ProjectileType::Blast(faction) => faction.clone(),
             ProjectileType::Bullet(faction) => faction.clone(),
+            ProjectileType::Beam(faction) => faction.clone(),
         }
     }
 }
@@ -70,32 +73,27 @@ pub enum MobType {
     Neutral(NeutralMobType),
 }
 
-impl MobType {
-    pub fn get_name(&self) -> String {
+impl LocalizeKey for MobType {
+    fn localize_key(&self) -> &'static str {
         match self {
             MobType::Enemy(enemy_type) => match enemy_type {
-                EnemyMobType::Pawn => "Pawn",
-                EnemyMobType::Drone => "Drone",
-                EnemyMobType::StraferRight | EnemyMobType::StraferLeft => "Strafer",
-                EnemyMobType::MissileLauncher => "Missile Launcher",
-                EnemyMobType::Missile => "Missile",
-                EnemyMobType::CrustlingRight | EnemyMobType::CrustlingLeft => "Crustling",
-                EnemyMobType::Ferritharax => "Ferritharax",
-                EnemyMobType::MechaFerritharax => "Mecha-Ferritharax",
-                EnemyMobType::MechaSaucetron => "Mecha-Saucetron",
-                EnemyMobType::Shelly => "Shelly",
-            },
-            MobType::Ally(ally_type) => match ally_type {
-                AllyMobType::Hauler2 => "Hauler",
-                AllyMobType::Hauler3 => "Hauler",
-                AllyMobType::TutorialHauler2 => "Hauler",
+                EnemyMobType::Pawn => "mob.pawn",
+                EnemyMobType::Drone => "mob.drone",
+                EnemyMobType::StraferRight | EnemyMobType::StraferLeft => "mob.strafer",
+                EnemyMobType::MissileLauncher => "mob.missile_launcher",
+                EnemyMobType::Missile => "mob.missile",
+                EnemyMobType::CrustlingRight | EnemyMobType::CrustlingLeft => "mob.crustling",
+                EnemyMobType::Ferritharax => "mob.ferritharax",
+                EnemyMobType::MechaFerritharax => "mob.mecha_ferritharax",
+                EnemyMobType::MechaSaucetron => "mob.mecha_saucetron",
+                EnemyMobType::Shelly => "mob.shelly",
             },
+            MobType::Ally(_) => "mob.hauler",
             MobType::Neutral(neutral_type) => match neutral_type {
-                NeutralMobType::MoneyAsteroid => "Money Asteroid",
-                NeutralMobType::TutorialDrone => "Tutorial Drone",
+                NeutralMobType::MoneyAsteroid => "mob.money_asteroid",
+                NeutralMobType::TutorialDrone => "mob.tutorial_drone",
             },
         }
-        .to_string()
     }
 }
 
@@ -241,6 +239,12 @@ pub struct SpawnMobEvent {
     pub boss: bool,
 }
 
+/// Kind of mob an entity is, reported in `MobDestroyedEvent` when it dies
+#[derive(Component)]
+pub struct MobComponent {
+    pub mob_type: MobType,
+}
+
 #[derive(Component)]
 pub struct ItemComponent {
     pub item_type: ItemType,
@@ -1,12 +1,16 @@
-use crate::components::audio::SoundEffectType;
-use crate::components::spawnable::{ProjectileType, SpawnPosition};
-use bevy::prelude::{Component, Timer, TimerMode, Vec2};
+use bevy::prelude::{Component, Vec2};
+use rand::{thread_rng, Rng};
 use serde::Deserialize;
-use std::{ops::Range, time::Duration};
+use std::f32::consts::FRAC_PI_2;
+use std::ops::Range;
 
-#[derive(Deserialize, Clone)]
+/// Determines how a turret handles its reload timer
+#[derive(Component, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum FireMode {
+    /// Fires as soon as it is reloaded
+    #[default]
     Automatic,
+    /// Holds a reloaded shot until the ship asks it to fire
     Manual,
 }
 
@@ -28,143 +32,68 @@ pub struct ArcPatternData {
 
 #[derive(Deserialize, Clone)]
 pub struct RandomPatternData {
+    /// Range of multipliers applied to the base speed of each projectile
     pub speed_range: Range<f32>,
+    /// Range of multipliers applied to the firing angle of each projectile
     pub angle_range: Range<f32>,
 }
 
-/// Stores data about a Weapon using minimal defining characteristics
-#[derive(Deserialize, Clone)]
-pub struct WeaponData {
-    /// Time between firing projectiles
-    pub reload_time: f32,
-    /// Initial delay before first projectile(s) can be spawned
-    pub initial_time: f32,
-    /// Determines how the weapon handles the reload timer
-    pub fire_mode: FireMode,
-    /// Maximum number of projectiles the weapon can have
-    pub capacity: usize,
-    /// Data about the projectiles fired from the weapon
-    pub projectile_data: WeaponProjectileData,
-}
-
-#[derive(Deserialize, Clone)]
-pub struct WeaponProjectileData {
-    /// Projectile type that the weapon spawns
-    pub ammunition: ProjectileType,
-    /// Damage of each projectile spawned by the weapon
-    pub damage: usize,
-    /// Position to spawn projectiles, either relative to the source or global
-    pub position: SpawnPosition,
-    /// Base speed of spawned projectiles
-    pub speed: f32,
-    /// Angle in radians of spawned projectiles
-    pub direction: f32,
-    /// Time before spawned projectiles despawn
-    pub despawn_time: f32,
-    /// Number of projectiles spawned at once
-    pub count: usize,
-    /// How projectiles are organized when they spawn
-    pub spread_pattern: SpreadPattern,
-    /// Size multiplier of the projectile
-    pub size: f32,
-    /// Sound that the weapon makes when fired
-    pub sound: SoundEffectType,
-}
-
-/// Describes how projectiles are spawned
-#[derive(Component, Clone)]
-pub struct WeaponsComponent {
-    pub weapons: Vec<Weapon>,
-}
-
-impl From<Vec<WeaponData>> for WeaponsComponent {
-    fn from(value: Vec<WeaponData>) -> Self {
-        let weapons = value
-            .iter()
-            .map(|weapon_data| Weapon::from(weapon_data))
-            .collect();
-
-        WeaponsComponent { weapons }
-    }
-}
-
-impl WeaponsComponent {
-    pub fn enable_all(&mut self) {
-        self.weapons
-            .iter_mut()
-            .for_each(|weapon| weapon.is_enabled = true);
+impl SpreadPattern {
+    /// Returns the velocities of `count` projectiles fired at `angle` radians with a base `speed`
+    pub fn velocities(&self, angle: f32, speed: f32, count: usize) -> Vec<Vec2> {
+        match self {
+            SpreadPattern::Arc(arc) => arc.velocities(angle, speed, count),
+            SpreadPattern::Random(random) => random.velocities(angle, speed, count),
+        }
     }
 
-    pub fn disable_all(&mut self) {
-        self.weapons
-            .iter_mut()
-            .for_each(|weapon| weapon.is_enabled = false);
+    /// Returns the velocities of `count` projectiles fired along `aim` with a base `speed`.
+    /// Patterns are authored facing up (like the abilities data), then rotated to face `aim`.
+    pub fn velocities_towards(&self, aim: Vec2, speed: f32, count: usize) -> Vec<Vec2> {
+        let rotation = Vec2::from_angle(aim.to_angle() - FRAC_PI_2);
+        self.velocities(FRAC_PI_2, speed, count)
+            .into_iter()
+            .map(|velocity| rotation.rotate(velocity))
+            .collect()
     }
 }
 
-#[derive(Component, Clone)]
-pub struct Weapon {
-    /// Base reload time of the weapon
-    pub base_reload_time: f32,
-    /// Tracks time until next projectile(s) can be spawned
-    pub reload_timer: Timer,
-    /// Initial delay before first projectile(s) can be spawned
-    pub initial_timer: Timer,
-    /// Determines how the weapon handles the reload timer
-    pub fire_mode: FireMode,
-    /// Maximum number of projectiles the weapon can have
-    pub capacity: usize,
-    /// Whether weapon is enabled
-    pub is_enabled: bool,
-    /// Data about the projectiles fired from the weapon
-    pub projectile_data: WeaponProjectileData,
-}
-
-impl From<&WeaponData> for Weapon {
-    fn from(value: &WeaponData) -> Self {
-        Weapon {
-            base_reload_time: value.reload_time,
-            reload_timer: Timer::from_seconds(value.reload_time, TimerMode::Once),
-            initial_timer: Timer::from_seconds(value.initial_time, TimerMode::Once),
-            fire_mode: value.fire_mode.clone(),
-            capacity: value.capacity,
-            projectile_data: value.projectile_data.clone(),
-            is_enabled: true,
+impl ArcPatternData {
+    fn velocities(&self, angle: f32, speed: f32, count: usize) -> Vec<Vec2> {
+        if count <= 1 {
+            return vec![Vec2::from_angle(angle) * speed * self.spread_weights];
         }
-    }
-}
-
-impl Weapon {
-    pub fn enable(&mut self) {
-        self.is_enabled = true;
-    }
 
-    pub fn disable_all(&mut self) {
-        self.is_enabled = false;
-    }
-    /// Returns ture if the weapon can be fired (<=> is currently reloaded)
-    pub fn can_fire(&self) -> bool {
-        self.reload_timer.finished()
-    }
+        // Spread evenly around the firing angle, but never wider than the max spread
+        let gaps = (count - 1) as f32;
+        let gap = self.projectile_gap.min(self.max_spread / gaps);
+        let first_angle = angle - gap * gaps / 2.0;
 
-    /// Returns the projectiles data if the weapon can be fired and resets the reload timer
-    pub fn fire_weapon(&mut self) -> Option<WeaponProjectileData> {
-        if self.can_fire() && self.is_enabled {
-            self.reload_timer.reset();
-            Some(self.projectile_data.clone())
-        } else {
-            None
-        }
+        (0..count)
+            .map(|idx| {
+                Vec2::from_angle(first_angle + gap * idx as f32) * speed * self.spread_weights
+            })
+            .collect()
     }
+}
 
-    /// Gain projectiles, but limit to the capacity of the weapon
-    pub fn gain_projectiles(&mut self, projectiles: usize) {
-        self.projectile_data.count = (self.projectile_data.count + projectiles).min(self.capacity);
+impl RandomPatternData {
+    fn velocities(&self, angle: f32, speed: f32, count: usize) -> Vec<Vec2> {
+        let mut rng = thread_rng();
+        (0..count)
+            .map(|_| {
+                let angle = angle * sample(&mut rng, &self.angle_range);
+                Vec2::from_angle(angle) * speed * sample(&mut rng, &self.speed_range)
+            })
+            .collect()
     }
+}
 
-    /// Set reload time to a new duration
-    pub fn set_reload_time(&mut self, new_reload_time_seconds: f32) {
-        self.reload_timer
-            .set_duration(Duration::from_secs_f32(new_reload_time_seconds));
+/// Samples a range, treating an empty range as a constant
+fn sample(rng: &mut impl Rng, range: &Range<f32>) -> f32 {
+    if range.is_empty() {
+        range.start
+    } else {
+        rng.gen_range(range.clone())
     }
 }
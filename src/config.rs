@@ -10,6 +10,9 @@ pub struct GameConfig {
     pub player_name: String,
     pub language: String,
     pub fullscreen: bool,
+    /// Let the game adapt to how the player is doing
+    #[serde(default)]
+    pub adaptive_difficulty: bool,
 }
 
 impl Default for GameConfig {
@@ -21,6 +24,7 @@ impl Default for GameConfig {
             player_name: "".to_string(),
             language: "English".to_string(),
             fullscreen: false,
+            adaptive_difficulty: false,
         }
     }
 }
@@ -1,7 +1,10 @@
 //! Development tools for the game. This plugin is only enabled in dev builds.
 
+use crate::enemy::director::SpawnDirector;
+use crate::gameplay::adaptive::AdaptiveDifficulty;
 use crate::gameplay::GameStates;
 use crate::screens::AppStates;
+use crate::theme::language::Localize;
 use bevy::{
     dev_tools::{
         states::log_transitions,
@@ -9,6 +12,7 @@ use bevy::{
     },
     input::common_conditions::input_just_pressed,
     prelude::*,
+    utils::HashSet,
 };
 
 pub(super) fn plugin(app: &mut App) {
@@ -22,10 +26,98 @@ pub(super) fn plugin(app: &mut App) {
         Update,
         toggle_debug_ui.run_if(input_just_pressed(TOGGLE_KEY)),
     );
+
+    // Toggle the spawn director readout.
+    app.add_systems(
+        Update,
+        (
+            toggle_director_overlay.run_if(input_just_pressed(DIRECTOR_KEY)),
+            update_director_overlay,
+        )
+            .chain()
+            .run_if(in_state(AppStates::Game)),
+    );
+
+    // Report untranslated cells and keywords requested without a translation row.
+    app.add_systems(Update, report_missing_translations);
 }
 
 const TOGGLE_KEY: KeyCode = KeyCode::Backspace;
 
+const DIRECTOR_KEY: KeyCode = KeyCode::F3;
+
 fn toggle_debug_ui(mut options: ResMut<UiDebugOptions>) {
     options.toggle();
 }
+
+#[derive(Component)]
+struct DirectorOverlay;
+
+fn toggle_director_overlay(mut commands: Commands, overlay: Query<Entity, With<DirectorOverlay>>) {
+    if let Ok(entity) = overlay.get_single() {
+        commands.entity(entity).despawn_recursive();
+        return;
+    }
+    commands.spawn((
+        Text::default(),
+        TextFont::from_font_size(14.0),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(10.0),
+            right: Val::Px(10.0),
+            ..default()
+        },
+        GlobalZIndex(10),
+        StateScoped(AppStates::Game),
+        DirectorOverlay,
+    ));
+}
+
+fn update_director_overlay(
+    director: Res<SpawnDirector>,
+    adaptive: Res<AdaptiveDifficulty>,
+    mut overlay: Query<&mut Text, With<DirectorOverlay>>,
+) {
+    for mut text in &mut overlay {
+        text.0 = format!(
+            "threat {:.1}  last wave {:.1}  elites {}  bosses {}",
+            director.threat, director.last_wave, director.elites, director.bosses
+        );
+        if adaptive.enabled {
+            text.0 += &format!(
+                "\nperformance {:.2}  damage {:.0}  kills {:.1}  health {:.2}\nbudget x{:.2}  accuracy {:.2}  drops x{:.2}",
+                adaptive.performance,
+                adaptive.recent_damage,
+                adaptive.recent_kills,
+                adaptive.health_ratio,
+                adaptive.spawn_budget(),
+                adaptive.enemy_accuracy(),
+                adaptive.drop_rate()
+            );
+        }
+    }
+}
+
+fn report_missing_translations(
+    localize: Res<Localize>,
+    mut coverage_reported: Local<bool>,
+    mut reported_keywords: Local<HashSet<String>>,
+) {
+    if !localize.is_initialized() {
+        return;
+    }
+    if !*coverage_reported {
+        *coverage_reported = true;
+        for (language, keyword) in localize.empty_cells() {
+            warn!("Missing {} translation for \"{}\"", language, keyword);
+        }
+    }
+    for keyword in localize.missing_keywords() {
+        if reported_keywords.insert(keyword.clone()) {
+            warn!(
+                "Translation keyword requested but not found: \"{}\"",
+                keyword
+            );
+        }
+    }
+}
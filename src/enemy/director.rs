@@ -0,0 +1,157 @@
+//! Spawn director: threat points are earned over time and spent on enemies from
+//! `assets/data/spawn_director.ron`, so a run ramps up predictably.
+use crate::assets::enemy_assets::MobAssets;
+use crate::components::spawnable::{EnemyMobType, MobType};
+use crate::enemy::drone::spawn_drone;
+use crate::enemy::drone_boss::spawn_drone_boss;
+use crate::enemy::fighter::spawn_fighter;
+use crate::enemy::mothership::spawn_mothership;
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+
+/// Enemies the director can buy
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum DirectorEnemy {
+    Drone,
+    Fighter,
+    DroneBoss,
+    Mothership,
+}
+
+impl DirectorEnemy {
+    pub fn mob_type(&self) -> MobType {
+        MobType::Enemy(match self {
+            DirectorEnemy::Drone => EnemyMobType::Drone,
+            DirectorEnemy::Fighter => EnemyMobType::MissileLauncher,
+            DirectorEnemy::DroneBoss => EnemyMobType::MechaSaucetron,
+            DirectorEnemy::Mothership => EnemyMobType::Ferritharax,
+        })
+    }
+
+    pub fn spawn(&self, commands: &mut Commands, mob_assets: &MobAssets, position: Vec3) {
+        let spawn_func = match self {
+            DirectorEnemy::Drone => spawn_drone,
+            DirectorEnemy::Fighter => spawn_fighter,
+            DirectorEnemy::DroneBoss => spawn_drone_boss,
+            DirectorEnemy::Mothership => spawn_mothership,
+        };
+        spawn_func(commands, mob_assets, position);
+    }
+}
+
+/// Enemies of a tier above `Regular` are capped by the director
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum Tier {
+    Regular,
+    Elite,
+    Boss,
+}
+
+#[derive(Deserialize, Debug, Clone, Reflect)]
+pub struct DirectorEntry {
+    pub enemy: DirectorEnemy,
+    /// Threat points spent to spawn one
+    pub cost: f32,
+    /// Game time before it can be bought
+    pub unlock_seconds: f32,
+    /// Relative chance among the affordable entries
+    pub weight: u32,
+    pub tier: Tier,
+}
+
+#[derive(Resource, Deserialize, Debug, Clone, Reflect)]
+pub struct DirectorTable {
+    /// Threat available for the first wave
+    pub starting_threat: f32,
+    /// Threat earned per second at the start of a run
+    pub threat_per_second: f32,
+    /// Extra threat per second earned for every minute played
+    pub threat_growth: f32,
+    /// Unspent threat stops accumulating past this
+    pub max_threat: f32,
+    pub wave_seconds: f32,
+    pub max_elites: u32,
+    pub max_bosses: u32,
+    pub enemies: Vec<DirectorEntry>,
+}
+
+impl DirectorTable {
+    /// Threat earned per second after `seconds` of game time
+    pub fn threat_income(&self, seconds: f32) -> f32 {
+        self.threat_per_second + self.threat_growth * seconds / 60.0
+    }
+
+    pub fn tier_of(&self, mob_type: &MobType) -> Tier {
+        self.enemies
+            .iter()
+            .find(|entry| entry.enemy.mob_type() == *mob_type)
+            .map(|entry| entry.tier)
+            .unwrap_or(Tier::Regular)
+    }
+
+    /// Elites and bosses stand out on the HUD
+    pub fn is_boss_or_elite(&self, mob_type: &MobType) -> bool {
+        self.tier_of(mob_type) != Tier::Regular
+    }
+
+    /// Only bosses get the boss theme, elites show up too often for it
+    pub fn is_boss(&self, mob_type: &MobType) -> bool {
+        self.tier_of(mob_type) == Tier::Boss
+    }
+
+    /// Picks a random unlocked enemy that fits the budget and the caps
+    pub fn pick(
+        &self,
+        seconds: f32,
+        director: &SpawnDirector,
+        rng: &mut impl Rng,
+    ) -> Option<&DirectorEntry> {
+        let candidates: Vec<&DirectorEntry> = self
+            .enemies
+            .iter()
+            .filter(|entry| entry.unlock_seconds <= seconds && entry.cost <= director.threat)
+            .filter(|entry| match entry.tier {
+                Tier::Regular => true,
+                Tier::Elite => director.elites < self.max_elites,
+                Tier::Boss => director.bosses < self.max_bosses,
+            })
+            .collect();
+        let total: u32 = candidates.iter().map(|entry| entry.weight).sum();
+        if total == 0 {
+            return None;
+        }
+        let mut roll = rng.gen_range(0..total);
+        candidates.into_iter().find(|entry| {
+            if roll < entry.weight {
+                true
+            } else {
+                roll -= entry.weight;
+                false
+            }
+        })
+    }
+}
+
+/// State of the director during a run, shown by the dev tools overlay
+#[derive(Resource, Debug, Default, Reflect)]
+pub struct SpawnDirector {
+    /// Unspent threat points
+    pub threat: f32,
+    pub elites: u32,
+    pub bosses: u32,
+    /// Threat spent on the last wave
+    pub last_wave: f32,
+}
+
+impl SpawnDirector {
+    pub fn buy(&mut self, entry: &DirectorEntry) {
+        self.threat -= entry.cost;
+        self.last_wave += entry.cost;
+        match entry.tier {
+            Tier::Regular => {}
+            Tier::Elite => self.elites += 1,
+            Tier::Boss => self.bosses += 1,
+        }
+    }
+}
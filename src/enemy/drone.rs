@@ -3,7 +3,7 @@ use std::f32::consts::PI;
 use super::AI;
 use crate::assets::enemy_assets::MobAssets;
 use crate::components::health::{Health, Spacecraft};
-use crate::components::spawnable::{EnemyMobType, MobType};
+use crate::components::spawnable::{EffectType, EnemyMobType, MobComponent, MobType};
 use crate::gameplay::gamelogic::ExplodesOnDespawn;
 use crate::gameplay::loot::{DropsLoot, WorthPoints};
 use crate::gameplay::physics::{BaseRotation, Collider, Physics};
@@ -11,7 +11,7 @@ use crate::ship::animation::AnimationComponent;
 use crate::ship::animation::AnimationDirection::PingPong;
 use crate::ship::animation::PingPongDirection::Forward;
 use crate::ship::engine::Engine;
-use crate::ship::turret::{DoesDamage, FireRate, Range, TurretBundle, TurretClass};
+use crate::ship::turret::{DoesDamage, FireRate, ProjectileArt, Range, TurretBundle, TurretClass};
 use bevy::prelude::*;
 
 pub fn spawn_drone(commands: &mut Commands, mob_assets: &MobAssets, position: Vec3) {
@@ -31,6 +31,7 @@ pub fn spawn_drone(commands: &mut Commands, mob_assets: &MobAssets, position: Ve
         ExplodesOnDespawn {
             size_min: 15.0,
             size_max: 20.0,
+            effect: Some(EffectType::MobExplosion),
             ..Default::default()
         },
     ));
@@ -42,12 +43,14 @@ pub fn spawn_drone(commands: &mut Commands, mob_assets: &MobAssets, position: Ve
         direction: PingPong(Forward),
     });
     entity.insert(AI);
+    entity.insert(MobComponent { mob_type });
     entity.insert(DropsLoot);
     entity.insert(WorthPoints { value: 10 });
     entity.with_children(|parent| {
         // Custom short range blast laser
         parent.spawn(TurretBundle {
             class: TurretClass::BlastLaser,
+            art: ProjectileArt::Beam,
             range: Range { max: 100.0 },
             fire_rate: FireRate::from_rate_in_seconds(2.0),
             damage: DoesDamage::from_amount(1),
@@ -1,7 +1,7 @@
 use super::AI;
 use crate::assets::enemy_assets::MobAssets;
 use crate::components::health::Spacecraft;
-use crate::components::spawnable::{EnemyMobType, MobType};
+use crate::components::spawnable::{EffectType, EnemyMobType, MobComponent, MobType};
 use crate::gameplay::gamelogic::ExplodesOnDespawn;
 use crate::gameplay::loot::{DropsLoot, WorthPoints};
 use crate::ship::animation::AnimationComponent;
@@ -12,7 +12,7 @@ use crate::{
     gameplay::physics::{BaseRotation, Collider, Physics},
     ship::{
         engine::Engine,
-        turret::{DoesDamage, FireRate, TurretBundle, TurretClass},
+        turret::{DoesDamage, FireRate, ProjectileArt, TurretBundle, TurretClass},
     },
 };
 use bevy::prelude::*;
@@ -35,6 +35,7 @@ pub fn spawn_fighter(commands: &mut Commands, mob_assets: &MobAssets, position:
         ExplodesOnDespawn {
             size_min: 20.0,
             size_max: 25.0,
+            effect: Some(EffectType::MobExplosion),
             ..Default::default()
         },
     ));
@@ -46,12 +47,14 @@ pub fn spawn_fighter(commands: &mut Commands, mob_assets: &MobAssets, position:
         direction: PingPong(Forward),
     });
     entity.insert(AI);
+    entity.insert(MobComponent { mob_type });
     entity.insert(DropsLoot);
     entity.insert(WorthPoints { value: 10 });
     entity.with_children(|parent| {
         // Custom Auto Cannon
         parent.spawn(TurretBundle {
             class: TurretClass::AutoCannon,
+            art: ProjectileArt::Bullet,
             fire_rate: FireRate::from_rate_in_seconds(1.0),
             damage: DoesDamage::from_amount(2),
             ..Default::default()
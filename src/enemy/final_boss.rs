@@ -1,7 +1,7 @@
 use super::{FinalBoss, AI};
 use crate::assets::enemy_assets::MobAssets;
 use crate::components::health::Spacecraft;
-use crate::components::spawnable::{EnemyMobType, MobType};
+use crate::components::spawnable::{EffectType, EnemyMobType, MobComponent, MobType};
 use crate::gameplay::gamelogic::ExplodesOnDespawn;
 use crate::gameplay::loot::{DropsLoot, WorthPoints};
 use crate::ship::animation::AnimationComponent;
@@ -12,7 +12,9 @@ use crate::{
     gameplay::physics::{BaseRotation, Collider, Physics},
     ship::{
         engine::{Engine, EngineMethod},
-        turret::{DoesDamage, EffectSize, FireRate, Range, TurretBundle, TurretClass},
+        turret::{
+            DoesDamage, EffectSize, FireRate, ProjectileArt, Range, TurretBundle, TurretClass,
+        },
     },
 };
 use bevy::prelude::*;
@@ -40,6 +42,7 @@ pub fn spawn_final_boss(commands: &mut Commands, mob_assets: &MobAssets, positio
         ExplodesOnDespawn {
             size_min: 15.0,
             size_max: 20.0,
+            effect: Some(EffectType::MobExplosion),
             ..Default::default()
         },
     ));
@@ -51,6 +54,7 @@ pub fn spawn_final_boss(commands: &mut Commands, mob_assets: &MobAssets, positio
         direction: PingPong(Forward),
     });
     entity.insert(AI);
+    entity.insert(MobComponent { mob_type });
     entity.insert(FinalBoss);
     entity.insert(DropsLoot);
     entity.insert(WorthPoints { value: 50 });
@@ -58,6 +62,7 @@ pub fn spawn_final_boss(commands: &mut Commands, mob_assets: &MobAssets, positio
         // Custom OP weapon
         parent.spawn(TurretBundle {
             class: TurretClass::PierceLaser,
+            art: ProjectileArt::Beam,
             range: Range { max: 300.0 },
             fire_rate: FireRate::from_rate_in_seconds(3.0),
             damage: DoesDamage::from_amount(5),
@@ -1,3 +1,4 @@
+pub mod director;
 mod drone;
 mod drone_boss;
 mod fighter;
@@ -5,26 +6,28 @@ mod final_boss;
 mod mothership;
 use bevy::prelude::*;
 
-use std::{cmp::min, time::Duration};
-
 use crate::assets::enemy_assets::MobAssets;
-use crate::enemy::drone::spawn_drone;
-use crate::enemy::drone_boss::spawn_drone_boss;
-use crate::enemy::fighter::spawn_fighter;
+use crate::components::spawnable::{MobComponent, SpawnMobEvent};
+use crate::enemy::director::{DirectorTable, SpawnDirector, Tier};
 use crate::enemy::final_boss::spawn_final_boss;
-use crate::enemy::mothership::spawn_mothership;
+use crate::gameplay::adaptive::AdaptiveDifficulty;
+use crate::gameplay::daily::RunModifiers;
+use crate::gameplay::difficulty::Difficulty;
 use crate::gameplay::gamelogic::{game_not_paused, GameTime};
+use crate::gameplay::mode::{BossProgress, GameMode};
 use crate::gameplay::physics::Physics;
 use crate::gameplay::player::PlayerComponent;
+use crate::gameplay::rng::RunRng;
 use crate::gameplay::GameStates;
 use crate::screens::AppStates;
 use crate::ship::engine::{Engine, EngineMethod};
 use crate::util::{Math, RenderLayer};
-use rand::Rng;
+use bevy::asset::ron::de::from_bytes;
 
 #[derive(Resource)]
 pub struct Spawning {
     pub max: u32,
+    /// Time between waves
     pub timer: Timer,
 }
 
@@ -35,7 +38,14 @@ pub struct AI;
 pub struct FinalBoss;
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_systems(OnEnter(AppStates::Game), spawn_startup)
+    app.register_type::<SpawnDirector>()
+        .insert_resource(
+            from_bytes::<DirectorTable>(include_bytes!("../../assets/data/spawn_director.ron"))
+                .unwrap(),
+        )
+        .init_resource::<SpawnDirector>()
+        .add_event::<SpawnMobEvent>()
+        .add_systems(OnEnter(AppStates::Game), spawn_startup)
         .add_systems(
             Update,
             ai_system
@@ -51,12 +61,24 @@ pub(super) fn plugin(app: &mut App) {
         );
 }
 
-fn spawn_startup(mut commands: Commands) {
+fn spawn_startup(
+    mut commands: Commands,
+    difficulty: Res<Difficulty>,
+    table: Res<DirectorTable>,
+    mut director: ResMut<SpawnDirector>,
+) {
     // Set spawn limit
-    let seconds = 30.0;
-    let mut timer = Timer::from_seconds(seconds, TimerMode::Repeating);
-    timer.set_elapsed(Duration::from_secs_f32(seconds));
-    commands.insert_resource(Spawning { max: 100, timer });
+    let mut timer = Timer::from_seconds(table.wave_seconds, TimerMode::Repeating);
+    // 开局立即生成第一波
+    timer.set_elapsed(timer.duration());
+    commands.insert_resource(Spawning {
+        max: difficulty.max_enemies(),
+        timer,
+    });
+    *director = SpawnDirector {
+        threat: table.starting_threat,
+        ..default()
+    };
 }
 
 fn spawner_system(
@@ -65,46 +87,63 @@ fn spawner_system(
     time: Res<Time>,
     game_time: Res<GameTime>,
     mut spawning: ResMut<Spawning>,
-    enemies_query: Query<Entity, With<AI>>,
+    table: Res<DirectorTable>,
+    mut director: ResMut<SpawnDirector>,
+    enemies_query: Query<Option<&MobComponent>, With<AI>>,
     player_query: Query<&Transform, With<PlayerComponent>>,
+    difficulty: Res<Difficulty>,
+    adaptive: Res<AdaptiveDifficulty>,
+    modifiers: Res<RunModifiers>,
+    mut rng: ResMut<RunRng>,
 ) {
-    let difficulty = game_time.0.elapsed_secs() as u32 / 30 + 1; // Goes from 1-20 difficulty in 10 minutes
-
-    spawning.timer.tick(time.delta() * difficulty); // Spawns quicker as time goes on
-
-    if spawning.timer.just_finished() {
-        if let Ok(player_transformation) = player_query.get_single() {
-            // pick a random location off screen from player
-            const DISTANCE_OFFSCREEN: f32 = 1000.0;
-            let spawn_point = player_transformation.translation.truncate()
-                + Math::random_2d_unit_vector() * DISTANCE_OFFSCREEN;
-
-            // Get current total amount of enemies
-            let num_enemies: u32 = enemies_query
-                .iter()
-                .len()
-                .try_into()
-                .unwrap_or(spawning.max);
-
-            let max_num_enemies_to_spawn = min(difficulty * 5, spawning.max - num_enemies); // Spawns more as time goes on
-
-            for _ in 0..max_num_enemies_to_spawn {
-                // Ensure they spawn in a pack not on top of each other
-                let jiggled_spawn = spawn_point + Math::random_2d_unit_vector() * 10.0;
-                let spawn_func = match rand::thread_rng().gen_range(0..100) {
-                    0 => spawn_mothership,
-                    1..=5 => spawn_drone_boss,
-                    6..=15 => spawn_fighter,
-                    _ => spawn_drone,
-                };
-                spawn_func(
-                    &mut commands,
-                    &mob_assets,
-                    jiggled_spawn.extend(RenderLayer::Enemy.as_z()),
-                );
-            }
+    let seconds = game_time.0.elapsed_secs();
+    let income = table.threat_income(seconds)
+        * difficulty.spawn_rate()
+        * adaptive.spawn_budget()
+        * modifiers.spawn_rate();
+    director.threat = (director.threat + income * time.delta_secs()).min(table.max_threat);
+
+    spawning.timer.tick(time.delta());
+    if !spawning.timer.just_finished() {
+        return;
+    }
+    let Ok(player_transformation) = player_query.get_single() else {
+        return;
+    };
+
+    // pick a random location off screen from player
+    const DISTANCE_OFFSCREEN: f32 = 1000.0;
+    let spawn_point = player_transformation.translation.truncate()
+        + Math::random_2d_unit_vector_from(&mut rng.rng) * DISTANCE_OFFSCREEN;
+
+    // Count the enemies alive, capped tiers separately
+    let mut num_enemies = 0;
+    director.elites = 0;
+    director.bosses = 0;
+    for mob in &enemies_query {
+        num_enemies += 1;
+        match mob.map(|mob| table.tier_of(&mob.mob_type)) {
+            Some(Tier::Elite) => director.elites += 1,
+            Some(Tier::Boss) => director.bosses += 1,
+            _ => {}
         }
     }
+
+    director.last_wave = 0.0;
+    while num_enemies < spawning.max {
+        let Some(entry) = table.pick(seconds, &director, &mut rng.rng) else {
+            break;
+        };
+        director.buy(entry);
+        num_enemies += 1;
+        // Ensure they spawn in a pack not on top of each other
+        let jiggled_spawn = spawn_point + Math::random_2d_unit_vector_from(&mut rng.rng) * 10.0;
+        entry.enemy.spawn(
+            &mut commands,
+            &mob_assets,
+            jiggled_spawn.extend(RenderLayer::Enemy.as_z()),
+        );
+    }
 }
 
 fn ai_system(
@@ -169,14 +208,22 @@ fn spawn_final_boss_system(
     game_time: Res<GameTime>,
     query: Query<(), With<FinalBoss>>,
     player_query: Query<&Transform, With<PlayerComponent>>,
+    difficulty: Res<Difficulty>,
+    mode: Res<GameMode>,
+    mut bosses: ResMut<BossProgress>,
+    modifiers: Res<RunModifiers>,
+    mut rng: ResMut<RunRng>,
 ) {
-    if (game_time.0.elapsed_secs() > 60.0 * 10.0) & query.is_empty() {
+    let seconds = game_time.0.elapsed_secs();
+    let boss_seconds = difficulty.final_boss_seconds() + modifiers.boss_delay_seconds();
+    if query.is_empty() && mode.boss_due(seconds, boss_seconds, &bosses) {
+        bosses.spawned += 1;
         // Spawn final boss
         let pos = player_query
             .get_single()
             .map(|transform| transform.translation.truncate())
             .unwrap_or_default();
-        let spawn_point = pos + Math::random_2d_unit_vector() * 1000.0;
+        let spawn_point = pos + Math::random_2d_unit_vector_from(&mut rng.rng) * 1000.0;
         spawn_final_boss(
             &mut commands,
             &mob_assets,
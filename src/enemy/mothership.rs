@@ -1,13 +1,13 @@
 use super::AI;
 use crate::assets::enemy_assets::MobAssets;
 use crate::components::health::Spacecraft;
-use crate::components::spawnable::{EnemyMobType, MobType};
+use crate::components::spawnable::{EffectType, EnemyMobType, MobComponent, MobType};
 use crate::gameplay::gamelogic::ExplodesOnDespawn;
 use crate::gameplay::loot::{DropsLoot, WorthPoints};
 use crate::ship::animation::AnimationComponent;
 use crate::ship::animation::AnimationDirection::PingPong;
 use crate::ship::animation::PingPongDirection::Forward;
-use crate::ship::turret::MultiShot;
+use crate::ship::turret::{MultiShot, Spread};
 use crate::{
     components::health::Health,
     gameplay::physics::{BaseRotation, Collider, Physics},
@@ -41,6 +41,7 @@ pub fn spawn_mothership(commands: &mut Commands, mob_assets: &MobAssets, positio
         ExplodesOnDespawn {
             size_min: 55.0,
             size_max: 65.0,
+            effect: Some(EffectType::MobExplosion),
             ..Default::default()
         },
     ));
@@ -52,6 +53,7 @@ pub fn spawn_mothership(commands: &mut Commands, mob_assets: &MobAssets, positio
         direction: PingPong(Forward),
     });
     entity.insert(AI);
+    entity.insert(MobComponent { mob_type });
     entity.insert(DropsLoot);
     entity.insert(WorthPoints { value: 50 });
     entity.with_children(|parent| {
@@ -62,6 +64,7 @@ pub fn spawn_mothership(commands: &mut Commands, mob_assets: &MobAssets, positio
             fire_rate: FireRate::from_rate_in_seconds(0.2),
             damage: DoesDamage::from_amount(5),
             shots: MultiShot { amount: 8 },
+            spread: Spread::rockets(),
             ..Default::default()
         });
     });
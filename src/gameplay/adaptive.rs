@@ -0,0 +1,156 @@
+//! Optional adaptive difficulty: watches how the run is going and nudges the spawn budget,
+//! enemy accuracy and loot drops within bounds.
+use crate::components::health::Health;
+use crate::components::spawnable::{MobDestroyedEvent, MobType};
+use crate::config::GameConfig;
+use crate::gameplay::mode::GameMode;
+use crate::gameplay::player::PlayerComponent;
+use crate::gameplay::GameStates;
+use crate::screens::AppStates;
+use bevy::prelude::*;
+use rand::Rng;
+use std::ops::Range;
+
+/// Seconds over which damage taken and kills fade out of the recent history
+const HISTORY_SECONDS: f32 = 20.0;
+
+/// Kills per second of a player keeping up with the waves
+const EXPECTED_KILL_RATE: f32 = 1.0;
+
+/// Seconds for the performance to catch up with what the player is doing
+const ADJUST_SECONDS: f32 = 10.0;
+
+/// Largest change of the spawn budget and drop rate
+const MAX_BUDGET_CHANGE: f32 = 0.3;
+const MAX_DROP_CHANGE: f32 = 0.3;
+
+/// Lowest enemy accuracy, reached when the player is struggling the most
+const MIN_ACCURACY: f32 = 0.75;
+
+/// Radians enemy turrets turn their aim off the player by on a missed shot
+const MISS_ANGLE: Range<f32> = 0.15..0.3;
+
+#[derive(Resource, Debug, Reflect)]
+pub struct AdaptiveDifficulty {
+    pub enabled: bool,
+    /// Damage taken by the player, fading over time
+    pub recent_damage: f32,
+    /// Hostile enemies destroyed, fading over time
+    pub recent_kills: f32,
+    /// Health and shields left, from 0 to 1
+    pub health_ratio: f32,
+    /// From -1 when struggling to 1 when cruising
+    pub performance: f32,
+    last_health: usize,
+}
+
+impl Default for AdaptiveDifficulty {
+    fn default() -> Self {
+        AdaptiveDifficulty {
+            enabled: false,
+            recent_damage: 0.0,
+            recent_kills: 0.0,
+            health_ratio: 1.0,
+            performance: 0.0,
+            last_health: 0,
+        }
+    }
+}
+
+impl AdaptiveDifficulty {
+    /// Multiplier of the threat earned by the spawn director
+    pub fn spawn_budget(&self) -> f32 {
+        if !self.enabled {
+            return 1.0;
+        }
+        1.0 + self.performance * MAX_BUDGET_CHANGE
+    }
+
+    /// Chance of an enemy shot at the player being aimed on target, only lowered for struggling
+    /// players
+    pub fn enemy_accuracy(&self) -> f32 {
+        if !self.enabled {
+            return 1.0;
+        }
+        1.0 - (1.0 - MIN_ACCURACY) * (-self.performance).max(0.0)
+    }
+
+    /// Multiplier of the loot dropped by enemies
+    pub fn drop_rate(&self) -> f32 {
+        if !self.enabled {
+            return 1.0;
+        }
+        1.0 - self.performance * MAX_DROP_CHANGE
+    }
+
+    /// Rolls the aim of an enemy shot at the player, returns the angle in radians to turn the
+    /// aim off target by, 0 when the shot is on target
+    pub fn aim_error(&self, rng: &mut impl Rng) -> f32 {
+        if rng.gen_range(0.0..1.0) < self.enemy_accuracy() {
+            return 0.0;
+        }
+        let angle = rng.gen_range(MISS_ANGLE);
+        if rng.gen_range(0..2) == 0 {
+            angle
+        } else {
+            -angle
+        }
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<AdaptiveDifficulty>()
+        .init_resource::<AdaptiveDifficulty>()
+        .add_systems(OnEnter(AppStates::Game), reset_adaptive_difficulty)
+        .add_systems(
+            Update,
+            track_performance
+                .run_if(in_state(GameStates::Playing))
+                .run_if(in_state(AppStates::Game)),
+        );
+}
+
+fn reset_adaptive_difficulty(
+    config: Res<GameConfig>,
+    mode: Res<GameMode>,
+    mut adaptive: ResMut<AdaptiveDifficulty>,
+) {
+    *adaptive = AdaptiveDifficulty {
+        // 每日挑战对所有玩家保持一致
+        enabled: config.adaptive_difficulty && *mode != GameMode::Daily,
+        ..default()
+    };
+}
+
+fn track_performance(
+    time: Res<Time>,
+    mut adaptive: ResMut<AdaptiveDifficulty>,
+    mut mob_destroyed_events: EventReader<MobDestroyedEvent>,
+    player: Query<&Health, With<PlayerComponent>>,
+) {
+    let Ok(health) = player.get_single() else {
+        return;
+    };
+    let delta = time.delta_secs();
+    let fade = (-delta / HISTORY_SECONDS).exp();
+    let current = health.health + health.shields;
+    let max = (health.max_health + health.max_shields).max(1);
+
+    // 护盾恢复不算作负伤害
+    let damage = adaptive.last_health.saturating_sub(current);
+    adaptive.last_health = current;
+    adaptive.recent_damage = adaptive.recent_damage * fade + damage as f32;
+    let kills = mob_destroyed_events
+        .read()
+        .filter(|ev| matches!(ev.mob_type, MobType::Enemy(_)))
+        .count();
+    adaptive.recent_kills = adaptive.recent_kills * fade + kills as f32;
+    adaptive.health_ratio = current as f32 / max as f32;
+
+    let kill_score =
+        (adaptive.recent_kills / HISTORY_SECONDS / EXPECTED_KILL_RATE - 1.0).clamp(-1.0, 1.0);
+    let health_score = adaptive.health_ratio * 2.0 - 1.0;
+    let damage_score = (adaptive.recent_damage / max as f32).clamp(0.0, 1.0);
+    let target = ((kill_score + health_score) / 2.0 - damage_score).clamp(-1.0, 1.0);
+    adaptive.performance += (target - adaptive.performance) * (delta / ADJUST_SECONDS).min(1.0);
+}
@@ -0,0 +1,166 @@
+//! Health bars at the top of the screen for every boss and elite on the field, with the hull and
+//! shields shown separately.
+use crate::assets::audio_assets::Fonts;
+use crate::components::health::Health;
+use crate::components::spawnable::MobComponent;
+use crate::enemy::director::DirectorTable;
+use crate::enemy::FinalBoss;
+use crate::screens::AppStates;
+use crate::theme::language::{LocalizeKey, LocalizeText};
+use crate::util::Colour;
+use bevy::prelude::*;
+
+/// Hull fractions marked on the bar, so players can tell how far into the fight they are
+const PHASE_MARKERS: [f32; 3] = [0.75, 0.5, 0.25];
+
+/// Column holding one panel per boss
+#[derive(Component)]
+struct BossBars;
+
+/// Panel of a boss, removed once the boss is gone
+#[derive(Component)]
+struct BossPanel(Entity);
+
+/// Filled part of a bar, sized after the health of the boss
+#[derive(Component)]
+struct BossBarFill {
+    boss: Entity,
+    shields: bool,
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(AppStates::Game), setup_boss_bars)
+        .add_systems(
+            Update,
+            (sync_boss_panels, update_boss_bars)
+                .chain()
+                .run_if(in_state(AppStates::Game)),
+        );
+}
+
+fn setup_boss_bars(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Boss Bars"),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(30.0),
+            top: Val::Px(5.0),
+            width: Val::Percent(40.0),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(6.0),
+            ..default()
+        },
+        BossBars,
+        StateScoped(AppStates::Game),
+    ));
+}
+
+/// Adds a panel for every new boss and removes the panels of bosses that are gone
+fn sync_boss_panels(
+    mut commands: Commands,
+    fonts: Res<Fonts>,
+    table: Res<DirectorTable>,
+    container: Query<Entity, With<BossBars>>,
+    panels: Query<(Entity, &BossPanel)>,
+    bosses: Query<(Entity, &MobComponent, Has<FinalBoss>), With<Health>>,
+) {
+    let Ok(container) = container.get_single() else {
+        return;
+    };
+    for (panel, boss) in &panels {
+        if !bosses.contains(boss.0) {
+            commands.entity(panel).despawn_recursive();
+        }
+    }
+    for (boss, mob, final_boss) in &bosses {
+        if !(final_boss || table.is_boss_or_elite(&mob.mob_type))
+            || panels.iter().any(|(_, panel)| panel.0 == boss)
+        {
+            continue;
+        }
+        commands.entity(container).with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(2.0),
+                        ..default()
+                    },
+                    BossPanel(boss),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new(""),
+                        TextFont {
+                            font: fonts.primary.clone(),
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(Colour::RED),
+                        LocalizeText::from_section(mob.mob_type.localize_key()),
+                    ));
+                    spawn_bar(panel, boss, false, 10.0, Colour::RED);
+                    spawn_bar(panel, boss, true, 4.0, Colour::SHIELD);
+                });
+        });
+    }
+}
+
+fn spawn_bar(panel: &mut ChildBuilder, boss: Entity, shields: bool, height: f32, colour: Color) {
+    panel
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(height),
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(Colour::BLACK.with_alpha(0.6)),
+            BorderColor(colour),
+        ))
+        .with_children(|bar| {
+            bar.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(colour),
+                BossBarFill { boss, shields },
+            ));
+            if shields {
+                return;
+            }
+            for marker in PHASE_MARKERS {
+                bar.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Percent(marker * 100.0),
+                        width: Val::Px(1.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(Colour::WHITE),
+                ));
+            }
+        });
+}
+
+fn update_boss_bars(mut fills: Query<(&BossBarFill, &mut Node)>, bosses: Query<&Health>) {
+    for (fill, mut node) in &mut fills {
+        let Ok(health) = bosses.get(fill.boss) else {
+            continue;
+        };
+        let (current, max) = if fill.shields {
+            (health.shields, health.max_shields)
+        } else {
+            (health.health, health.max_health)
+        };
+        let fraction = if max == 0 {
+            0.0
+        } else {
+            current.min(max) as f32 / max as f32
+        };
+        node.width = Val::Percent(fraction * 100.0);
+    }
+}
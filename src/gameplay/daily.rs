@@ -0,0 +1,278 @@
+//! Daily challenge: the seed, difficulty and run modifiers come from the current date, and only
+//! the first attempt of the day is scored. Attempts are persisted in the `PkvStore`.
+use crate::gameplay::difficulty::Difficulty;
+use crate::gameplay::highscore::record_run;
+use crate::gameplay::loot::Points;
+use crate::gameplay::mode::GameMode;
+use crate::gameplay::rng::RunRng;
+use crate::gameplay::GameStates;
+use crate::screens::AppStates;
+use crate::theme::language::LocalizeKey;
+use bevy::prelude::*;
+use bevy::utils::SystemTime;
+use bevy_pkv::PkvStore;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+
+const DAILY_KEY: &str = "daily_challenge";
+
+/// Modifiers active in a daily challenge
+const DAILY_MODIFIERS: usize = 2;
+
+/// Changes to the rules of a run
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RunModifier {
+    /// Enemies arrive faster
+    Swarm,
+    /// Tougher enemies
+    Armored,
+    /// The player starts with half the shields
+    Fragile,
+    /// More loot
+    Bountiful,
+    /// The final boss shows up later
+    Marathon,
+}
+
+impl RunModifier {
+    pub const ALL: [RunModifier; 5] = [
+        RunModifier::Swarm,
+        RunModifier::Armored,
+        RunModifier::Fragile,
+        RunModifier::Bountiful,
+        RunModifier::Marathon,
+    ];
+
+    pub fn describe(&self) -> String {
+        format!("{}.desc", self.localize_key())
+    }
+}
+
+impl LocalizeKey for RunModifier {
+    fn localize_key(&self) -> &'static str {
+        match self {
+            RunModifier::Swarm => "modifier.swarm",
+            RunModifier::Armored => "modifier.armored",
+            RunModifier::Fragile => "modifier.fragile",
+            RunModifier::Bountiful => "modifier.bountiful",
+            RunModifier::Marathon => "modifier.marathon",
+        }
+    }
+}
+
+/// Modifiers of the current run
+#[derive(Resource, Debug, Default)]
+pub struct RunModifiers(pub Vec<RunModifier>);
+
+impl RunModifiers {
+    fn has(&self, modifier: RunModifier) -> bool {
+        self.0.contains(&modifier)
+    }
+
+    /// Multiplier of the threat earned by the spawn director
+    pub fn spawn_rate(&self) -> f32 {
+        if self.has(RunModifier::Swarm) {
+            1.5
+        } else {
+            1.0
+        }
+    }
+
+    pub fn enemy_health(&self) -> f32 {
+        if self.has(RunModifier::Armored) {
+            1.5
+        } else {
+            1.0
+        }
+    }
+
+    pub fn player_shields(&self) -> f32 {
+        if self.has(RunModifier::Fragile) {
+            0.5
+        } else {
+            1.0
+        }
+    }
+
+    pub fn drop_rate(&self) -> f32 {
+        if self.has(RunModifier::Bountiful) {
+            1.5
+        } else {
+            1.0
+        }
+    }
+
+    /// Extra game time before the final boss
+    pub fn boss_delay_seconds(&self) -> f32 {
+        if self.has(RunModifier::Marathon) {
+            120.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Today's challenge, the same for every player on the same UTC day
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct DailyChallenge {
+    /// `YYYY-MM-DD`
+    pub date: String,
+    pub seed: u64,
+    pub difficulty: Difficulty,
+    pub modifiers: Vec<RunModifier>,
+}
+
+impl DailyChallenge {
+    /// Challenge of a day counted from 1970-01-01
+    pub fn for_day(day: u64) -> Self {
+        let (year, month, day_of_month) = civil_from_days(day as i64);
+        let seed = splitmix64(day);
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut modifiers = RunModifier::ALL.to_vec();
+        modifiers.shuffle(&mut rng);
+        modifiers.truncate(DAILY_MODIFIERS);
+        DailyChallenge {
+            date: format!("{:04}-{:02}-{:02}", year, month, day_of_month),
+            seed,
+            difficulty: if seed % 2 == 0 {
+                Difficulty::Normal
+            } else {
+                Difficulty::Hard
+            },
+            modifiers,
+        }
+    }
+
+    pub fn today() -> Self {
+        let day = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs() / 86_400)
+            .unwrap_or_default();
+        DailyChallenge::for_day(day)
+    }
+}
+
+/// Year, month and day of a day counted from 1970-01-01
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Spreads consecutive days over unrelated seeds
+pub fn splitmix64(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Last day a daily challenge was attempted, persisted in the `PkvStore`
+#[derive(Resource, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DailyAttempt {
+    pub date: String,
+    /// Score of the scored attempt, None until it ends
+    pub score: Option<u32>,
+}
+
+impl DailyAttempt {
+    pub fn attempted(&self, challenge: &DailyChallenge) -> bool {
+        self.date == challenge.date
+    }
+}
+
+/// Whether the current run is the scored daily attempt
+#[derive(Resource, Default)]
+pub struct DailyScored(pub bool);
+
+pub(super) fn plugin(app: &mut App) {
+    app.insert_resource(DailyChallenge::today())
+        .init_resource::<RunModifiers>()
+        .init_resource::<DailyAttempt>()
+        .init_resource::<DailyScored>()
+        .add_systems(Startup, load_daily_attempt)
+        .add_systems(Update, save_daily_attempt)
+        .add_systems(OnEnter(AppStates::NewRun), refresh_daily_challenge)
+        .add_systems(OnEnter(AppStates::Game), begin_run)
+        .add_systems(
+            OnEnter(GameStates::GameOver),
+            finish_daily_attempt.before(record_run),
+        );
+}
+
+fn load_daily_attempt(pkv: Res<PkvStore>, mut attempt: ResMut<DailyAttempt>) {
+    if let Ok(v) = pkv.get::<String>(DAILY_KEY) {
+        if let Ok(deserialized) = serde_json::from_str(v.as_str()) {
+            *attempt = deserialized;
+        }
+    }
+}
+
+fn save_daily_attempt(mut pkv: ResMut<PkvStore>, attempt: Res<DailyAttempt>) {
+    if attempt.is_changed() && !attempt.is_added() {
+        if let Ok(serialized) = serde_json::to_string(&attempt.into_inner()) {
+            if let Err(err) = pkv.set::<String>(DAILY_KEY, &serialized) {
+                warn!("Failed to save daily challenge: {}", err);
+            }
+        } else {
+            warn!("Failed to serialize daily challenge");
+        }
+    }
+}
+
+pub fn refresh_daily_challenge(mut challenge: ResMut<DailyChallenge>) {
+    let today = DailyChallenge::today();
+    if *challenge != today {
+        *challenge = today;
+    }
+}
+
+/// Seeds the run, and spends the day's scored attempt when starting a daily challenge
+fn begin_run(
+    mode: Res<GameMode>,
+    challenge: Res<DailyChallenge>,
+    mut attempt: ResMut<DailyAttempt>,
+    mut scored: ResMut<DailyScored>,
+    mut modifiers: ResMut<RunModifiers>,
+    mut rng: ResMut<RunRng>,
+) {
+    if *mode != GameMode::Daily {
+        scored.0 = false;
+        modifiers.0.clear();
+        *rng = RunRng::default();
+        return;
+    }
+    scored.0 = !attempt.attempted(&challenge);
+    if scored.0 {
+        *attempt = DailyAttempt {
+            date: challenge.date.clone(),
+            score: None,
+        };
+    }
+    modifiers.0 = challenge.modifiers.clone();
+    *rng = RunRng::new(challenge.seed);
+}
+
+fn finish_daily_attempt(
+    scored: Res<DailyScored>,
+    points: Res<Points>,
+    mut attempt: ResMut<DailyAttempt>,
+) {
+    if scored.0 {
+        attempt.score = Some(points.value);
+    }
+}
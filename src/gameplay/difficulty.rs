@@ -0,0 +1,140 @@
+//! Difficulty presets picked before a run, scaling the spawner and the enemies.
+use crate::components::health::Health;
+use crate::enemy::{FinalBoss, AI};
+use crate::gameplay::daily::RunModifiers;
+use crate::gameplay::gamelogic::GameTime;
+use crate::gameplay::mode::{BossProgress, GameMode};
+use crate::screens::AppStates;
+use crate::ship::turret::DoesDamage;
+use crate::theme::language::LocalizeKey;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Nightmare,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 4] = [
+        Difficulty::Easy,
+        Difficulty::Normal,
+        Difficulty::Hard,
+        Difficulty::Nightmare,
+    ];
+
+    /// Multiplier of the threat the spawn director earns per second
+    pub fn spawn_rate(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.75,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.25,
+            Difficulty::Nightmare => 1.5,
+        }
+    }
+
+    /// Most enemies alive at once
+    pub fn max_enemies(&self) -> u32 {
+        match self {
+            Difficulty::Easy => 70,
+            Difficulty::Normal => 100,
+            Difficulty::Hard => 130,
+            Difficulty::Nightmare => 160,
+        }
+    }
+
+    /// Multiplier of enemy armor and shields
+    pub fn enemy_health(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.7,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.5,
+            Difficulty::Nightmare => 2.0,
+        }
+    }
+
+    /// Multiplier of enemy weapon damage
+    pub fn enemy_damage(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.5,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.5,
+            Difficulty::Nightmare => 2.0,
+        }
+    }
+
+    /// Multiplier of the cargo needed to level up
+    pub fn experience(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.8,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.2,
+            Difficulty::Nightmare => 1.5,
+        }
+    }
+
+    /// Game time before the final boss shows up
+    pub fn final_boss_seconds(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 60.0 * 12.0,
+            Difficulty::Normal => 60.0 * 10.0,
+            Difficulty::Hard => 60.0 * 9.0,
+            Difficulty::Nightmare => 60.0 * 8.0,
+        }
+    }
+}
+
+impl LocalizeKey for Difficulty {
+    fn localize_key(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "difficulty.easy",
+            Difficulty::Normal => "difficulty.normal",
+            Difficulty::Hard => "difficulty.hard",
+            Difficulty::Nightmare => "difficulty.nightmare",
+        }
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<Difficulty>()
+        .add_systems(Update, scale_enemies.run_if(in_state(AppStates::Game)));
+}
+
+fn scale(value: usize, multiplier: f32) -> usize {
+    (value as f32 * multiplier).round() as usize
+}
+
+/// Applies the difficulty, game mode and run modifiers to enemies as they spawn
+fn scale_enemies(
+    difficulty: Res<Difficulty>,
+    mode: Res<GameMode>,
+    bosses: Res<BossProgress>,
+    game_time: Res<GameTime>,
+    modifiers: Res<RunModifiers>,
+    mut enemies: Query<(&mut Health, Option<&Children>, Has<FinalBoss>), Added<AI>>,
+    mut turrets: Query<&mut DoesDamage>,
+) {
+    let seconds = game_time.0.elapsed_secs();
+    for (mut health, children, is_boss) in &mut enemies {
+        let multiplier = difficulty.enemy_health()
+            * mode.enemy_health(seconds, is_boss, &bosses)
+            * modifiers.enemy_health();
+        if multiplier == 1.0 && difficulty.enemy_damage() == 1.0 {
+            continue;
+        }
+        health.max_health = scale(health.max_health, multiplier).max(1);
+        health.health = health.max_health;
+        health.max_shields = scale(health.max_shields, multiplier);
+        health.shields = health.max_shields;
+
+        for child in children.into_iter().flatten() {
+            if let Ok(mut damage) = turrets.get_mut(*child) {
+                damage.multiplier = difficulty.enemy_damage();
+            }
+        }
+    }
+}
@@ -1,6 +1,10 @@
-use crate::gameplay::gamelogic::game_not_paused;
+use crate::assets::effect::EffectAssets;
+use crate::components::events::AnimationCompletedEvent;
+use crate::components::spawnable::EffectType;
+use crate::gameplay::gamelogic::{game_not_paused, DespawnWithScene};
 use crate::screens::AppStates;
-use crate::util::Colour;
+use crate::ship::animation::{AnimationComponent, AnimationDirection};
+use crate::util::{Colour, RenderLayer};
 use crate::AppSet;
 use bevy::prelude::*;
 
@@ -45,10 +49,21 @@ impl Default for HitFlash {
     }
 }
 
+/// A one-shot spritesheet effect, despawned when its animation completes
+#[derive(Component)]
+pub struct Effect;
+
+/// How long each frame of a spritesheet effect is shown
+const EFFECT_FRAME_DURATION: f32 = 0.05;
+
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(
         Update,
-        (floating_text_system, hit_flash_system)
+        (
+            floating_text_system,
+            hit_flash_system,
+            effect_despawn_system,
+        )
             .chain()
             .in_set(AppSet::Update)
             .distributive_run_if(game_not_paused)
@@ -100,3 +115,44 @@ pub fn hit_flash_system(time: Res<Time>, mut query: Query<(&mut Sprite, &mut Hit
         }
     }
 }
+
+/// Plays a spritesheet effect once at a position, `size` overrides the size of the sprite in pixels
+pub fn spawn_effect(
+    commands: &mut Commands,
+    effect_assets: &EffectAssets,
+    effect_type: &EffectType,
+    position: Vec2,
+    size: Option<f32>,
+) {
+    let (Some(image), Some(layout)) = (
+        effect_assets.get_image(effect_type),
+        effect_assets.get_texture_atlas_layout(effect_type),
+    ) else {
+        return;
+    };
+    commands.spawn((
+        Effect,
+        Sprite {
+            custom_size: size.map(Vec2::splat),
+            ..Sprite::from_atlas_image(image, TextureAtlas::from(layout))
+        },
+        Transform::from_translation(position.extend(RenderLayer::Effects.as_z())),
+        AnimationComponent {
+            timer: Timer::from_seconds(EFFECT_FRAME_DURATION, TimerMode::Repeating),
+            direction: AnimationDirection::Forward,
+        },
+        DespawnWithScene,
+    ));
+}
+
+pub fn effect_despawn_system(
+    mut commands: Commands,
+    mut animation_completed: EventReader<AnimationCompletedEvent>,
+    query: Query<Entity, With<Effect>>,
+) {
+    for ev in animation_completed.read() {
+        if query.contains(ev.0) {
+            commands.entity(ev.0).despawn();
+        }
+    }
+}
@@ -1,21 +1,27 @@
-use crate::assets::audio_assets::{AudioAssets, Fonts};
+use crate::assets::audio_assets::Fonts;
+use crate::assets::effect::EffectAssets;
+use crate::components::audio::{PlaySoundEffectEvent, SoundEffectType};
 use crate::components::health::Health;
-use crate::config::GameConfig;
+use crate::components::spawnable::{EffectType, Faction, MobComponent, MobDestroyedEvent};
+use crate::enemy::FinalBoss;
+use crate::gameplay::adaptive::AdaptiveDifficulty;
 use crate::gameplay::camera::camera_follow;
-use crate::gameplay::effects::{FloatingText, HitFlash};
+use crate::gameplay::daily::RunModifiers;
+use crate::gameplay::difficulty::Difficulty;
+use crate::gameplay::effects::{spawn_effect, FloatingText, HitFlash};
 use crate::gameplay::loot::{DropsLoot, IsLoot, Points, WorthPoints};
 use crate::gameplay::physics::{Collider, Physics};
 use crate::gameplay::player::PlayerComponent;
+use crate::gameplay::rng::RunRng;
 use crate::gameplay::GameStates;
 use crate::screens::AppStates;
-use crate::ship::bullet::{ExplosionRender, ShouldDespawn};
+use crate::ship::bullet::{DamageSource, ExplosionRender, ShouldDespawn};
+use crate::theme::language::LocalizeFont;
 use crate::util::{Colour, RenderLayer};
 use crate::{AppSet, CameraShake};
 use bevy::app::App;
 use bevy::prelude::*;
 use bevy::time::Stopwatch;
-use bevy_kira_audio::prelude::Volume;
-use bevy_kira_audio::{Audio, AudioControl};
 use bevy_parallax::ParallaxSystems;
 use bevy_prototype_lyon::prelude::{GeometryBuilder, ShapeBundle, Stroke};
 use bevy_prototype_lyon::shapes;
@@ -34,6 +40,8 @@ pub struct ExplodesOnDespawn {
     pub duration_max: f32,
     pub size_min: f32,
     pub size_max: f32,
+    /// Spritesheet played for each explosion instead of the expanding circle
+    pub effect: Option<EffectType>,
 }
 
 impl Default for ExplodesOnDespawn {
@@ -47,6 +55,7 @@ impl Default for ExplodesOnDespawn {
             size_min: 40.0,
             size_max: 40.0,
             spread: 10.0,
+            effect: None,
         }
     }
 }
@@ -57,11 +66,13 @@ pub struct GameTime(pub Stopwatch);
 #[derive(Resource)]
 pub struct PlayerLevel {
     pub value: usize,
+    /// Difficulty multiplier of the cargo needed per level
+    pub experience_scale: f32,
 }
 
 impl PlayerLevel {
     pub fn required_cargo_to_level(&self) -> usize {
-        self.value * 4 // TODO make exponential?
+        (self.value as f32 * 4.0 * self.experience_scale).round() as usize // TODO make exponential?
     }
 }
 
@@ -75,6 +86,8 @@ pub struct Damage {
 pub struct TakeDamageEvent {
     pub entity: Entity,
     pub damage: Damage,
+    /// Turret and ship that dealt the damage, None for collisions
+    pub source: Option<DamageSource>,
 }
 
 #[derive(PartialEq)]
@@ -92,6 +105,16 @@ impl Default for Targettable {
     }
 }
 
+impl Targettable {
+    /// Faction used to pick projectile and effect art for this side
+    pub fn faction(&self) -> Faction {
+        match self.0 {
+            Allegiance::Friend => Faction::Ally,
+            Allegiance::Enemy => Faction::Enemy,
+        }
+    }
+}
+
 #[derive(Component)]
 pub struct WillTarget(pub Vec<Allegiance>);
 
@@ -103,6 +126,7 @@ impl Default for WillTarget {
 
 pub(super) fn plugin(app: &mut App) {
     app.add_event::<TakeDamageEvent>()
+        .add_event::<MobDestroyedEvent>()
         .add_systems(OnEnter(AppStates::Game), setup_new_game);
     app.add_systems(OnExit(AppStates::Game), reset_game);
     app.add_systems(
@@ -120,7 +144,7 @@ pub(super) fn plugin(app: &mut App) {
             .distributive_run_if(in_state(AppStates::Game)),
     );
 }
-fn setup_new_game(mut commands: Commands) {
+fn setup_new_game(mut commands: Commands, difficulty: Res<Difficulty>) {
     // Set the start time
     commands.insert_resource(GameTime::default());
 
@@ -128,11 +152,17 @@ fn setup_new_game(mut commands: Commands) {
     commands.insert_resource(Points { value: 0 });
 
     // Start player at level 0 so they get immediate selection
-    commands.insert_resource(PlayerLevel { value: 0 });
+    commands.insert_resource(PlayerLevel {
+        value: 0,
+        experience_scale: difficulty.experience(),
+    });
 }
 
 pub fn game_not_paused(game_state: Res<State<GameStates>>) -> bool {
-    *game_state.get() != GameStates::Paused && *game_state.get() != GameStates::Selection
+    !matches!(
+        game_state.get(),
+        GameStates::Paused | GameStates::Selection | GameStates::Victory
+    )
 }
 
 fn game_time_system(time: Res<Time>, mut game_time: ResMut<GameTime>) {
@@ -153,12 +183,10 @@ fn reset_game(
 pub fn combat_system(
     mut commands: Commands,
     time: Res<Time>,
-    mut query: Query<(&mut Health, Entity), Without<ShouldDespawn>>,
-    sound_assets: Res<AudioAssets>,
-    audio: Res<Audio>,
-    config: Res<GameConfig>,
+    mut query: Query<(&mut Health, Entity, &Transform), Without<ShouldDespawn>>,
+    mut sound_event: EventWriter<PlaySoundEffectEvent>,
 ) {
-    for (mut health, entity) in &mut query {
+    for (mut health, entity, transform) in &mut query {
         if health.health <= 0 {
             commands.entity(entity).insert(ShouldDespawn);
             continue;
@@ -174,10 +202,10 @@ pub fn combat_system(
             if health.shields_recharge_timer.just_finished() {
                 health.shields += 1;
                 //播放增加护盾的音效
-                // play_sound_effects(&audio,&config, sound_assets.shield_up.clone(),position.translation.truncate());
-                audio
-                    .play(sound_assets.shield_up.clone())
-                    .with_volume(Volume::Amplitude(config.sfx_volume as f64));
+                sound_event.send(PlaySoundEffectEvent::at(
+                    SoundEffectType::ShieldUp,
+                    transform.translation.truncate(),
+                ));
             }
         }
     }
@@ -192,14 +220,14 @@ pub fn take_damage_events(
         &mut Health,
         Option<&PlayerComponent>,
         Option<&mut HitFlash>,
+        Option<&FinalBoss>,
     )>,
     mut camera: Query<&mut CameraShake>,
-    sound_assets: Res<AudioAssets>,
-    audio: Res<Audio>,
-    config: Res<GameConfig>,
+    mut sound_event: EventWriter<PlaySoundEffectEvent>,
 ) {
     for ev in take_damage_events.read() {
-        if let Ok((transform, mut health, is_player, hit_flash)) = query.get_mut(ev.entity) {
+        if let Ok((transform, mut health, is_player, hit_flash, is_boss)) = query.get_mut(ev.entity)
+        {
             health.take_damage(ev.damage.amount);
 
             //玩家受击时带有相机抖动效果
@@ -208,14 +236,20 @@ pub fn take_damage_events(
                     shake.trauma = ev.damage.amount.clamp(0, 5) as f32;
                 }
                 //播放玩家被击中音效
-                audio
-                    .play(sound_assets.bullet_hit_1.clone())
-                    .with_volume(Volume::Amplitude(config.sfx_volume as f64));
+                sound_event.send(PlaySoundEffectEvent::at(
+                    SoundEffectType::BulletHitPlayer,
+                    transform.translation.truncate(),
+                ));
             } else {
                 //播放敌人被击中音效
-                audio
-                    .play(sound_assets.bullet_hit_2.clone())
-                    .with_volume(Volume::Amplitude(config.sfx_volume as f64));
+                sound_event.send(PlaySoundEffectEvent::at(
+                    if is_boss.is_some() {
+                        SoundEffectType::BulletHitBoss
+                    } else {
+                        SoundEffectType::BulletHitMob
+                    },
+                    transform.translation.truncate(),
+                ));
                 // Floating Text
                 commands.spawn((
                     FloatingText::default(),
@@ -230,6 +264,7 @@ pub fn take_damage_events(
                     } else {
                         Colour::WHITE
                     }),
+                    LocalizeFont,
                     TextLayout {
                         justify: JustifyText::Center,
                         ..default()
@@ -260,24 +295,47 @@ pub fn death_system(
             Option<&PlayerComponent>,
             Option<&ExplodesOnDespawn>,
             Option<&WorthPoints>,
+            Option<&MobComponent>,
+            Option<&FinalBoss>,
         ),
         With<ShouldDespawn>,
     >,
     mut game_state: ResMut<NextState<GameStates>>,
     mut points: ResMut<Points>,
-    sound_assets: Res<AudioAssets>,
-    audio: Res<Audio>,
-    config: Res<GameConfig>,
+    effect_assets: Res<EffectAssets>,
+    mut sound_event: EventWriter<PlaySoundEffectEvent>,
+    mut mob_destroyed_event: EventWriter<MobDestroyedEvent>,
+    adaptive: Res<AdaptiveDifficulty>,
+    modifiers: Res<RunModifiers>,
+    mut rng: ResMut<RunRng>,
 ) {
-    for (entity, drops_loot, transform, is_player, explodes, worth_points) in &mut query {
+    let drop_rate = adaptive.drop_rate() * modifiers.drop_rate();
+    for (entity, drops_loot, transform, is_player, explodes, worth_points, mob, is_boss) in
+        &mut query
+    {
         commands.entity(entity).despawn_recursive();
 
+        if let Some(mob) = mob {
+            mob_destroyed_event.send(MobDestroyedEvent {
+                mob_type: mob.mob_type.clone(),
+                entity,
+                is_boss: is_boss.is_some(),
+            });
+        }
+
         if let Some(transform) = transform {
             if let Some(_drops_loot) = drops_loot {
-                spawn_loot(&mut commands, &fonts, transform.translation);
+                let count = (rng.rng.gen_range(1..=3) as f32 * drop_rate).round() as usize;
+                spawn_loot(&mut commands, &fonts, transform.translation, count);
             }
             if let Some(explodes) = explodes {
-                explode(&mut commands, explodes, transform.translation.truncate());
+                explode(
+                    &mut commands,
+                    &effect_assets,
+                    &mut sound_event,
+                    explodes,
+                    transform.translation.truncate(),
+                );
             }
         }
 
@@ -287,17 +345,15 @@ pub fn death_system(
 
         if is_player.is_some() {
             //播放失败音效
-            audio
-                .play(sound_assets.game_over.clone())
-                .with_volume(Volume::Amplitude(config.sfx_volume as f64));
+            sound_event.send(PlaySoundEffectEvent::new(SoundEffectType::GameOver));
             game_state.set(GameStates::GameOver);
         }
     }
 }
 
-fn spawn_loot(commands: &mut Commands, fonts: &Res<Fonts>, position: Vec3) {
+fn spawn_loot(commands: &mut Commands, fonts: &Res<Fonts>, position: Vec3, count: usize) {
     let mut rng = rand::thread_rng();
-    let loots = (0..rng.gen_range(1..=3))
+    let loots = (0..count)
         .map(|_| {
             (
                 IsLoot,
@@ -332,7 +388,20 @@ fn spawn_loot(commands: &mut Commands, fonts: &Res<Fonts>, position: Vec3) {
     commands.spawn_batch(loots);
 }
 
-fn explode(commands: &mut Commands, explodes: &ExplodesOnDespawn, position: Vec2) {
+fn explode(
+    commands: &mut Commands,
+    effect_assets: &EffectAssets,
+    sound_event: &mut EventWriter<PlaySoundEffectEvent>,
+    explodes: &ExplodesOnDespawn,
+    position: Vec2,
+) {
+    // 特效爆炸不会生成 ExplosionRender，整个死亡只播放一次音效
+    if explodes.effect.is_some() {
+        sound_event.send(PlaySoundEffectEvent::at(
+            SoundEffectType::MobExplosion,
+            position,
+        ));
+    }
     // Spawn several explosions
     let mut rng = rand::thread_rng();
     let amount = rng.gen_range(explodes.amount_min..=explodes.amount_max);
@@ -341,10 +410,21 @@ fn explode(commands: &mut Commands, explodes: &ExplodesOnDespawn, position: Vec2
             x: rng.gen_range(-explodes.spread..=explodes.spread),
             y: rng.gen_range(-explodes.spread..=explodes.spread),
         };
+        let radius = rng.gen_range(explodes.size_min..=explodes.size_max);
+        if let Some(effect) = &explodes.effect {
+            spawn_effect(
+                commands,
+                effect_assets,
+                effect,
+                position + offset,
+                Some(radius * 2.0),
+            );
+            continue;
+        }
         commands.spawn((
             ExplosionRender {
                 origin: position + offset,
-                radius: rng.gen_range(explodes.size_min..=explodes.size_max),
+                radius,
                 ttl: Timer::from_seconds(
                     rng.gen_range(explodes.duration_min..=explodes.duration_max),
                     TimerMode::Once,
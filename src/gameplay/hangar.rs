@@ -0,0 +1,161 @@
+//! Credits carried over between runs and the permanent upgrades bought with them in the hangar.
+use crate::gameplay::highscore::record_run;
+use crate::gameplay::loot::Points;
+use crate::gameplay::statistics::RunStatistics;
+use crate::gameplay::GameStates;
+use crate::theme::language::LocalizeKey;
+use bevy::prelude::*;
+use bevy_pkv::PkvStore;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const HANGAR_KEY: &str = "hangar";
+
+/// Points needed for one credit, collected loot is worth a credit each
+const POINTS_PER_CREDIT: u32 = 10;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HangarUpgrade {
+    /// More shields at the start of a run
+    StartingShields,
+    /// Rerolls of the level up options per run
+    Reroll,
+    /// More starting weapons to choose from
+    WeaponChoice,
+    /// Bigger loot magnet range
+    MagnetRadius,
+}
+
+impl HangarUpgrade {
+    pub const ALL: [HangarUpgrade; 4] = [
+        HangarUpgrade::StartingShields,
+        HangarUpgrade::Reroll,
+        HangarUpgrade::WeaponChoice,
+        HangarUpgrade::MagnetRadius,
+    ];
+
+    pub fn max_level(&self) -> u8 {
+        match self {
+            HangarUpgrade::StartingShields => 5,
+            HangarUpgrade::Reroll => 3,
+            HangarUpgrade::WeaponChoice => 1,
+            HangarUpgrade::MagnetRadius => 5,
+        }
+    }
+
+    /// Credits needed to buy the level after `level`
+    pub fn cost(&self, level: u8) -> u32 {
+        let base = match self {
+            HangarUpgrade::StartingShields => 50,
+            HangarUpgrade::Reroll => 100,
+            HangarUpgrade::WeaponChoice => 300,
+            HangarUpgrade::MagnetRadius => 40,
+        };
+        base * (level as u32 + 1)
+    }
+
+    pub fn describe(&self) -> String {
+        format!("{}.desc", self.localize_key())
+    }
+}
+
+impl LocalizeKey for HangarUpgrade {
+    fn localize_key(&self) -> &'static str {
+        match self {
+            HangarUpgrade::StartingShields => "hangar.starting_shields",
+            HangarUpgrade::Reroll => "hangar.reroll",
+            HangarUpgrade::WeaponChoice => "hangar.weapon_choice",
+            HangarUpgrade::MagnetRadius => "hangar.magnet_radius",
+        }
+    }
+}
+
+/// Credits and permanent upgrades, persisted in the `PkvStore`
+#[derive(Resource, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Hangar {
+    pub credits: u32,
+    pub upgrades: HashMap<HangarUpgrade, u8>,
+}
+
+impl Hangar {
+    pub fn level(&self, upgrade: HangarUpgrade) -> u8 {
+        self.upgrades.get(&upgrade).copied().unwrap_or(0)
+    }
+
+    /// Cost of the next level, None once the upgrade is maxed
+    pub fn next_cost(&self, upgrade: HangarUpgrade) -> Option<u32> {
+        let level = self.level(upgrade);
+        (level < upgrade.max_level()).then(|| upgrade.cost(level))
+    }
+
+    /// Spends credits on the next level, returns false if it can't be afforded or is maxed
+    pub fn buy(&mut self, upgrade: HangarUpgrade) -> bool {
+        match self.next_cost(upgrade) {
+            Some(cost) if cost <= self.credits => {
+                self.credits -= cost;
+                *self.upgrades.entry(upgrade).or_insert(0) += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn bonus_shields(&self) -> usize {
+        self.level(HangarUpgrade::StartingShields) as usize * 10
+    }
+
+    pub fn rerolls(&self) -> u8 {
+        self.level(HangarUpgrade::Reroll)
+    }
+
+    pub fn magnet_range_multiplier(&self) -> f32 {
+        1.0 + self.level(HangarUpgrade::MagnetRadius) as f32 * 0.15
+    }
+}
+
+/// Credits earned by the last run, shown on the game over screen
+#[derive(Resource, Default)]
+pub struct RunCredits(pub u32);
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<Hangar>()
+        .init_resource::<RunCredits>()
+        .add_systems(Startup, load_hangar)
+        .add_systems(Update, save_hangar)
+        .add_systems(
+            OnEnter(GameStates::GameOver),
+            award_credits.after(record_run),
+        );
+}
+
+fn load_hangar(pkv: Res<PkvStore>, mut hangar: ResMut<Hangar>) {
+    if let Ok(v) = pkv.get::<String>(HANGAR_KEY) {
+        if let Ok(deserialized) = serde_json::from_str(v.as_str()) {
+            *hangar = deserialized;
+        }
+    }
+}
+
+fn save_hangar(mut pkv: ResMut<PkvStore>, hangar: Res<Hangar>) {
+    if hangar.is_changed() && !hangar.is_added() {
+        if let Ok(serialized) = serde_json::to_string(&hangar.into_inner()) {
+            if let Err(err) = pkv.set::<String>(HANGAR_KEY, &serialized) {
+                warn!("Failed to save hangar: {}", err);
+            }
+        } else {
+            warn!("Failed to serialize hangar");
+        }
+    }
+}
+
+/// Converts the run's points and collected loot into credits
+pub fn award_credits(
+    points: Res<Points>,
+    statistics: Res<RunStatistics>,
+    mut hangar: ResMut<Hangar>,
+    mut run_credits: ResMut<RunCredits>,
+) {
+    let credits = points.value / POINTS_PER_CREDIT + statistics.loot_collected as u32;
+    run_credits.0 = credits;
+    hangar.credits += credits;
+}
@@ -0,0 +1,180 @@
+//! Local high-score table, persisted in the `PkvStore` next to the game config.
+use crate::config::GameConfig;
+use crate::gameplay::daily::DailyScored;
+use crate::gameplay::difficulty::Difficulty;
+use crate::gameplay::gamelogic::{GameTime, PlayerLevel};
+use crate::gameplay::loot::Points;
+use crate::gameplay::mode::GameMode;
+use crate::gameplay::upgrade::PlayerUpgrades;
+use crate::gameplay::GameStates;
+use crate::theme::language::LocalizeKey;
+use bevy::prelude::*;
+use bevy_pkv::PkvStore;
+use serde::{Deserialize, Serialize};
+
+/// Number of runs kept in the table of each game mode
+pub const MAX_HIGH_SCORES: usize = 10;
+
+const HIGH_SCORES_KEY: &str = "high_scores";
+
+/// Name recorded when the player confirms an empty name
+pub const DEFAULT_PLAYER_NAME: &str = "Pilot";
+
+/// A finished run
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HighScore {
+    pub name: String,
+    pub score: u32,
+    /// Survival time in seconds
+    pub seconds: u64,
+    pub level: usize,
+    /// Localization keywords of the weapons picked during the run
+    pub weapons: Vec<String>,
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    #[serde(default)]
+    pub mode: GameMode,
+}
+
+/// Best runs of all game modes, highest score first
+#[derive(Resource, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct HighScores(pub Vec<HighScore>);
+
+impl HighScores {
+    /// Table of one game mode, highest score first
+    pub fn table(&self, mode: GameMode) -> Vec<&HighScore> {
+        self.0
+            .iter()
+            .filter(|high_score| high_score.mode == mode)
+            .collect()
+    }
+
+    /// Whether a run with this score would enter the table of its mode
+    pub fn qualifies(&self, mode: GameMode, score: u32) -> bool {
+        let table = self.table(mode);
+        score > 0
+            && (table.len() < MAX_HIGH_SCORES
+                || table.last().is_some_and(|lowest| score > lowest.score))
+    }
+
+    /// Inserts a run and returns its rank in the table of its mode, None if it didn't make it
+    pub fn insert(&mut self, entry: HighScore) -> Option<usize> {
+        let mode = entry.mode;
+        let rank = self
+            .table(mode)
+            .iter()
+            .filter(|high_score| high_score.score >= entry.score)
+            .count();
+        if rank >= MAX_HIGH_SCORES {
+            return None;
+        }
+        let index = self
+            .0
+            .iter()
+            .position(|high_score| entry.score > high_score.score)
+            .unwrap_or(self.0.len());
+        self.0.insert(index, entry);
+        // 只保留该模式的前 MAX_HIGH_SCORES 名
+        let mut kept = 0;
+        self.0.retain(|high_score| {
+            if high_score.mode != mode {
+                return true;
+            }
+            kept += 1;
+            kept <= MAX_HIGH_SCORES
+        });
+        Some(rank)
+    }
+}
+
+/// A run that made the table and is waiting for the player to enter a name
+#[derive(Resource)]
+pub struct PendingHighScore(pub HighScore);
+
+impl PendingHighScore {
+    /// Saves the run under the entered name, which becomes the default for the next record
+    pub fn commit(&self, high_scores: &mut HighScores, config: &mut GameConfig) -> Option<usize> {
+        let mut entry = self.0.clone();
+        if entry.name.trim().is_empty() {
+            entry.name = DEFAULT_PLAYER_NAME.to_string();
+        }
+        config.player_name = entry.name.clone();
+        high_scores.insert(entry)
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<HighScores>()
+        .add_systems(Startup, load_high_scores)
+        .add_systems(Update, save_high_scores)
+        .add_systems(OnEnter(GameStates::GameOver), record_run)
+        .add_systems(OnExit(GameStates::GameOver), commit_pending_high_score);
+}
+
+fn load_high_scores(pkv: Res<PkvStore>, mut high_scores: ResMut<HighScores>) {
+    if let Ok(v) = pkv.get::<String>(HIGH_SCORES_KEY) {
+        if let Ok(deserialized) = serde_json::from_str(v.as_str()) {
+            *high_scores = deserialized;
+        }
+    }
+}
+
+fn save_high_scores(mut pkv: ResMut<PkvStore>, high_scores: Res<HighScores>) {
+    if high_scores.is_changed() && !high_scores.is_added() {
+        if let Ok(serialized) = serde_json::to_string(&high_scores.into_inner()) {
+            if let Err(err) = pkv.set::<String>(HIGH_SCORES_KEY, &serialized) {
+                warn!("Failed to save high scores: {}", err);
+            }
+        } else {
+            warn!("Failed to serialize high scores");
+        }
+    }
+}
+
+/// Keeps the finished run aside when it deserves a place in the table
+pub fn record_run(
+    mut commands: Commands,
+    high_scores: Res<HighScores>,
+    config: Res<GameConfig>,
+    points: Res<Points>,
+    game_time: Res<GameTime>,
+    level: Res<PlayerLevel>,
+    upgrades: Res<PlayerUpgrades>,
+    difficulty: Res<Difficulty>,
+    mode: Res<GameMode>,
+    daily_scored: Res<DailyScored>,
+) {
+    // 每日挑战只有当天第一次尝试计分
+    if *mode == GameMode::Daily && !daily_scored.0 {
+        return;
+    }
+    if !high_scores.qualifies(*mode, points.value) {
+        return;
+    }
+    commands.insert_resource(PendingHighScore(HighScore {
+        name: config.player_name.clone(),
+        score: points.value,
+        seconds: game_time.0.elapsed().as_secs(),
+        level: level.value,
+        weapons: upgrades
+            .weapons()
+            .iter()
+            .map(|weapon| weapon.localize_key().to_string())
+            .collect(),
+        difficulty: *difficulty,
+        mode: *mode,
+    }));
+}
+
+/// Leaving the game over screen without confirming a name still saves the record
+pub fn commit_pending_high_score(
+    mut commands: Commands,
+    pending: Option<Res<PendingHighScore>>,
+    mut high_scores: ResMut<HighScores>,
+    mut config: ResMut<GameConfig>,
+) {
+    if let Some(pending) = pending {
+        pending.commit(&mut high_scores, &mut config);
+        commands.remove_resource::<PendingHighScore>();
+    }
+}
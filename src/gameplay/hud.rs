@@ -7,7 +7,7 @@ use crate::gameplay::upgrade::PlayerUpgrades;
 use crate::screens::AppStates;
 use crate::ship::engine::Engine;
 use crate::ship::turret::{FireRate, TurretClass};
-use crate::theme::language::Localize;
+use crate::theme::language::{Localize, LocalizeFont};
 use crate::util::Colour;
 use bevy::prelude::*;
 
@@ -48,6 +48,7 @@ fn setup_hud(mut commands: Commands, fonts: Res<Fonts>) {
                     ..Default::default()
                 },
                 TextColor(Colour::WHITE.into()),
+                LocalizeFont,
             ));
 
             parent.spawn((
@@ -58,6 +59,7 @@ fn setup_hud(mut commands: Commands, fonts: Res<Fonts>) {
                     ..Default::default()
                 },
                 TextColor(Colour::SHIELD.into()),
+                LocalizeFont,
             ));
 
             parent.spawn((
@@ -68,6 +70,7 @@ fn setup_hud(mut commands: Commands, fonts: Res<Fonts>) {
                     ..Default::default()
                 },
                 TextColor(Colour::RED.into()),
+                LocalizeFont,
             ));
 
             parent.spawn((
@@ -78,6 +81,7 @@ fn setup_hud(mut commands: Commands, fonts: Res<Fonts>) {
                     ..Default::default()
                 },
                 TextColor(Colour::INACTIVE.into()),
+                LocalizeFont,
             ));
 
             parent.spawn((
@@ -88,6 +92,7 @@ fn setup_hud(mut commands: Commands, fonts: Res<Fonts>) {
                     ..Default::default()
                 },
                 TextColor(Colour::PLAYER.into()),
+                LocalizeFont,
             ));
         });
 
@@ -117,6 +122,7 @@ fn setup_hud(mut commands: Commands, fonts: Res<Fonts>) {
                         ..Default::default()
                     },
                     TextColor(Colour::WHITE.into()),
+                    LocalizeFont,
                 ));
             }
         });
@@ -148,6 +154,7 @@ fn setup_hud(mut commands: Commands, fonts: Res<Fonts>) {
                         ..default()
                     },
                     TextColor(Colour::WHITE.into()),
+                    LocalizeFont,
                 ));
             }
         });
@@ -182,30 +189,43 @@ pub fn hud_system(
         for (children, ui_node) in &mut query {
             let displays = match ui_node {
                 UINode::Status => vec![
-                    format!(
-                        "{:<8} {} {}",
-                        localize.get("Armor"),
-                        bar(health.health, health.max_health, 10),
-                        health.health
+                    localize.format(
+                        "hud.armor",
+                        &[
+                            ("bar", &bar(health.health, health.max_health, 10)),
+                            ("armor", &health.health),
+                        ],
                     ),
-                    format!(
-                        "{:<8} {} {}",
-                        localize.get("Shield"),
-                        bar(health.shields, health.max_shields, 10),
-                        health.shields
+                    localize.format(
+                        "hud.shield",
+                        &[
+                            ("bar", &bar(health.shields, health.max_shields, 10)),
+                            ("shield", &health.shields),
+                        ],
                     ),
-                    format!(
-                        "{:<8} {} {:0>2}",
-                        localize.get("Level"),
-                        bar(cargo.amount, level.required_cargo_to_level(), 10),
-                        level.value
+                    localize.format(
+                        "hud.level",
+                        &[
+                            (
+                                "bar",
+                                &bar(cargo.amount, level.required_cargo_to_level(), 10),
+                            ),
+                            ("level", &format!("{:0>2}", level.value)),
+                        ],
                     ),
-                    format!("{:<8} {} m/s", localize.get("Speed"), engine.speed.round()),
-                    format!(
-                        "{:<8} {:0>2}:{:0>2}",
-                        localize.get("Time"),
-                        game_time.0.elapsed().as_secs() / 60,
-                        game_time.0.elapsed().as_secs() % 60
+                    localize.format("hud.speed", &[("speed", &engine.speed.round())]),
+                    localize.format(
+                        "hud.time",
+                        &[
+                            (
+                                "minutes",
+                                &format!("{:0>2}", game_time.0.elapsed().as_secs() / 60),
+                            ),
+                            (
+                                "seconds",
+                                &format!("{:0>2}", game_time.0.elapsed().as_secs() % 60),
+                            ),
+                        ],
                     ),
                 ],
                 UINode::Equipment => {
@@ -217,7 +237,7 @@ pub fn hud_system(
                             format!(
                                 "{} {:>16}",
                                 bar((fire_rate.timer.fraction() * 10.0).round() as usize, 10, 10),
-                                localize.get(format!("{:>16}", class).as_str()),
+                                localize.get_key(class),
                             )
                         })
                         .collect::<Vec<String>>();
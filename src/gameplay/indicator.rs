@@ -0,0 +1,149 @@
+//! Arrows at the edge of the screen pointing at enemies, bosses and loot outside the view of the
+//! [`MainCamera`]. Closer targets get bigger arrows.
+use crate::components::spawnable::MobComponent;
+use crate::enemy::director::DirectorTable;
+use crate::enemy::FinalBoss;
+use crate::gameplay::gamelogic::{Allegiance, Targettable};
+use crate::gameplay::loot::IsLoot;
+use crate::screens::AppStates;
+use crate::util::{Colour, RenderLayer};
+use crate::MainCamera;
+use bevy::prelude::*;
+use bevy_prototype_lyon::prelude::{shapes, Fill, GeometryBuilder, ShapeBundle};
+use std::cmp::Ordering;
+
+/// Arrows shown at once, bosses and the closest targets come first
+const MAX_INDICATORS: usize = 24;
+
+/// Screen distance between the arrows and the edge of the screen
+const EDGE_MARGIN: f32 = 24.0;
+
+/// Distance past the edge of the screen at which arrows reach their smallest size
+const FAR_DISTANCE: f32 = 1500.0;
+
+/// Loot further than this from the centre of the screen is not worth pointing at
+const LOOT_RANGE: f32 = 1000.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Target {
+    Boss,
+    Enemy,
+    Loot,
+}
+
+impl Target {
+    fn colour(&self) -> Color {
+        match self {
+            Target::Boss => Colour::RED,
+            Target::Enemy => Colour::ENEMY,
+            Target::Loot => Colour::PURPLE,
+        }
+    }
+
+    fn size(&self) -> f32 {
+        match self {
+            Target::Boss => 1.5,
+            Target::Enemy => 1.0,
+            Target::Loot => 0.7,
+        }
+    }
+}
+
+#[derive(Component)]
+struct OffscreenIndicator;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(AppStates::Game), spawn_indicators)
+        .add_systems(Update, update_indicators.run_if(in_state(AppStates::Game)));
+}
+
+/// Spawns a hidden pool of arrows, pointing right until placed
+fn spawn_indicators(mut commands: Commands) {
+    let arrow = shapes::Polygon {
+        points: vec![
+            Vec2::new(10.0, 0.0),
+            Vec2::new(-6.0, 7.0),
+            Vec2::new(-6.0, -7.0),
+        ],
+        closed: true,
+    };
+    for _ in 0..MAX_INDICATORS {
+        commands.spawn((
+            OffscreenIndicator,
+            ShapeBundle {
+                path: GeometryBuilder::build_as(&arrow),
+                transform: Transform::from_xyz(0.0, 0.0, RenderLayer::Effects.as_z()),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            Fill::color(Colour::ENEMY),
+            StateScoped(AppStates::Game),
+        ));
+    }
+}
+
+fn update_indicators(
+    table: Res<DirectorTable>,
+    camera: Query<(&GlobalTransform, &OrthographicProjection), With<MainCamera>>,
+    enemies: Query<
+        (&Transform, &Targettable, &MobComponent, Has<FinalBoss>),
+        Without<OffscreenIndicator>,
+    >,
+    loot: Query<&Transform, (With<IsLoot>, Without<OffscreenIndicator>)>,
+    mut indicators: Query<(&mut Transform, &mut Fill, &mut Visibility), With<OffscreenIndicator>>,
+) {
+    let Ok((camera, projection)) = camera.get_single() else {
+        return;
+    };
+    let centre = camera.translation().truncate();
+    let half_size = projection.area.half_size();
+    let inner = (half_size - Vec2::splat(EDGE_MARGIN * projection.scale)).max(Vec2::ONE);
+
+    let enemies = enemies
+        .iter()
+        .filter(|(_, targettable, _, _)| targettable.0 == Allegiance::Enemy)
+        .map(|(transform, _, mob, final_boss)| {
+            let target = if final_boss || table.is_boss_or_elite(&mob.mob_type) {
+                Target::Boss
+            } else {
+                Target::Enemy
+            };
+            (transform, target)
+        });
+    let mut targets: Vec<(Vec2, Target)> = enemies
+        .chain(loot.iter().map(|transform| (transform, Target::Loot)))
+        .map(|(transform, target)| (transform.translation.truncate() - centre, target))
+        .filter(|(offset, target)| {
+            let on_screen = offset.x.abs() <= half_size.x && offset.y.abs() <= half_size.y;
+            !on_screen && (*target != Target::Loot || offset.length() <= LOOT_RANGE)
+        })
+        .collect();
+    targets.sort_by(|(a, a_target), (b, b_target)| {
+        a_target.cmp(b_target).then(
+            a.length_squared()
+                .partial_cmp(&b.length_squared())
+                .unwrap_or(Ordering::Equal),
+        )
+    });
+
+    let mut targets = targets.into_iter();
+    for (mut transform, mut fill, mut visibility) in &mut indicators {
+        let Some((offset, target)) = targets.next() else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+        // 把目标方向缩放到屏幕边缘内
+        let edge = (inner.x / offset.x.abs()).min(inner.y / offset.y.abs());
+        let beyond = offset.length() * (1.0 - edge);
+        let scale = target.size()
+            * (1.2 - 0.6 * (beyond / FAR_DISTANCE).clamp(0.0, 1.0))
+            * projection.scale;
+        transform.translation = (centre + offset * edge).extend(transform.translation.z);
+        transform.rotation = Quat::from_rotation_z(offset.to_angle());
+        transform.scale = Vec3::splat(scale);
+        if fill.color != target.colour() {
+            fill.color = target.colour();
+        }
+        *visibility = Visibility::Inherited;
+    }
+}
@@ -1,5 +1,9 @@
+use crate::assets::effect::EffectAssets;
+use crate::components::spawnable::EffectType;
+use crate::gameplay::effects::spawn_effect;
 use crate::gameplay::gamelogic::game_not_paused;
 use crate::gameplay::physics::{Collider, Physics};
+use crate::gameplay::rng::RunRng;
 use crate::screens::AppStates;
 use crate::AppSet;
 use bevy::app::{App, Update};
@@ -26,6 +30,13 @@ pub struct WorthPoints {
 #[derive(Component)]
 pub struct IsLoot;
 
+/// Sent when a piece of loot is picked up
+#[derive(Event)]
+pub struct LootCollectedEvent {
+    /// Cargo gained, including the bonus
+    pub cargo: usize,
+}
+
 #[derive(Component)]
 pub struct DropsLoot;
 
@@ -51,7 +62,7 @@ impl Magnet {
 }
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_systems(
+    app.add_event::<LootCollectedEvent>().add_systems(
         Update,
         (loot_magnet_system, loot_cargo_collision)
             .chain()
@@ -107,6 +118,9 @@ pub fn loot_cargo_collision(
         ),
     >,
     mut points: ResMut<Points>,
+    effect_assets: Res<EffectAssets>,
+    mut loot_collected_event: EventWriter<LootCollectedEvent>,
+    mut rng: ResMut<RunRng>,
 ) {
     for (mut cargo, transform, collider) in &mut query {
         for (loot_transform, loot_entity, loot_collider, worth_points) in &loot_query {
@@ -117,10 +131,13 @@ pub fn loot_cargo_collision(
                 <= loot_collider.radius + collider.radius
             {
                 // Increase cargo
-                cargo.amount += 1;
-                if rand::thread_rng().gen_range(0.0..1.0) < cargo.bonus_chance {
-                    cargo.amount += 2;
-                }
+                let gained = if rng.rng.gen_range(0.0..1.0) < cargo.bonus_chance {
+                    3
+                } else {
+                    1
+                };
+                cargo.amount += gained;
+                loot_collected_event.send(LootCollectedEvent { cargo: gained });
 
                 // Add points
                 if let Some(worth_points) = worth_points {
@@ -128,6 +145,13 @@ pub fn loot_cargo_collision(
                 }
 
                 // Despawn
+                spawn_effect(
+                    &mut commands,
+                    &effect_assets,
+                    &EffectType::ConsumableDespawn,
+                    loot_transform.translation.truncate(),
+                    None,
+                );
                 if let Some(mut subcommand) = commands.get_entity(loot_entity) {
                     subcommand.despawn(); // Direct despawn because adding ShouldDespawn has issues
                 }
@@ -1,21 +1,34 @@
 //! The game's main gameplay states and transitions between them.
 
+pub mod adaptive;
+mod boss_bar;
 mod camera;
+pub mod daily;
+pub mod difficulty;
 pub mod effects;
 pub mod gamelogic;
+pub mod hangar;
+pub mod highscore;
 mod hud;
+mod indicator;
 pub mod level;
 pub mod loot;
+pub mod mode;
 mod object;
 pub mod physics;
 pub mod player;
+mod radar;
+pub mod rng;
 mod selection;
-mod upgrade;
+pub mod statistics;
+pub mod upgrade;
+pub mod victory;
 
 use bevy::prelude::*;
 
 pub(super) fn plugin(app: &mut App) {
     app.init_state::<GameStates>();
+    app.enable_state_scoped_entities::<GameStates>();
 
     app.add_plugins((
         gamelogic::plugin,
@@ -25,9 +38,27 @@ pub(super) fn plugin(app: &mut App) {
         selection::plugin,
         upgrade::plugin,
         object::plugin,
-        hud::plugin,
         player::plugin,
     ));
+    // HUD
+    app.add_plugins((
+        hud::plugin,
+        radar::plugin,
+        indicator::plugin,
+        boss_bar::plugin,
+    ));
+    // Run rules and results
+    app.add_plugins((
+        highscore::plugin,
+        statistics::plugin,
+        hangar::plugin,
+        victory::plugin,
+        difficulty::plugin,
+        adaptive::plugin,
+        mode::plugin,
+        rng::plugin,
+        daily::plugin,
+    ));
 }
 
 /// The game's gameplay states.
@@ -38,4 +69,6 @@ pub enum GameStates {
     Selection,
     Paused,
     GameOver,
+    /// The final boss was destroyed, the run can end or go on endlessly
+    Victory,
 }
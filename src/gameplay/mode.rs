@@ -0,0 +1,117 @@
+//! Game modes picked on the title screen, deciding when bosses show up and how a run is won.
+use crate::components::spawnable::MobDestroyedEvent;
+use crate::gameplay::gamelogic::GameTime;
+use crate::gameplay::GameStates;
+use crate::screens::AppStates;
+use crate::theme::language::LocalizeKey;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Bosses to destroy to win a boss rush
+pub const BOSS_RUSH_BOSSES: u32 = 5;
+
+/// Game time between a boss rush kill and the next boss
+const BOSS_RUSH_BREAK_SECONDS: f32 = 5.0;
+
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum GameMode {
+    /// Survive until the final boss and destroy it
+    #[default]
+    Classic,
+    /// Never ends, a boss shows up periodically and enemies keep getting tougher
+    Endless,
+    /// Bosses back to back
+    BossRush,
+    /// Classic with the seed, difficulty and modifiers of the day
+    Daily,
+}
+
+impl GameMode {
+    pub const ALL: [GameMode; 4] = [
+        GameMode::Classic,
+        GameMode::Endless,
+        GameMode::BossRush,
+        GameMode::Daily,
+    ];
+
+    /// Whether the next boss should show up, `boss_seconds` is the difficulty's final boss time
+    pub fn boss_due(&self, seconds: f32, boss_seconds: f32, bosses: &BossProgress) -> bool {
+        match self {
+            GameMode::Classic | GameMode::Daily => bosses.spawned == 0 && seconds > boss_seconds,
+            GameMode::Endless => seconds > boss_seconds * (bosses.spawned + 1) as f32,
+            GameMode::BossRush => {
+                bosses.spawned < BOSS_RUSH_BOSSES
+                    && seconds > bosses.last_defeat_seconds + BOSS_RUSH_BREAK_SECONDS
+            }
+        }
+    }
+
+    /// Whether destroying this many bosses wins the run
+    pub fn is_won(&self, bosses_defeated: u32) -> bool {
+        match self {
+            GameMode::Classic | GameMode::Daily => bosses_defeated >= 1,
+            GameMode::Endless => false,
+            GameMode::BossRush => bosses_defeated >= BOSS_RUSH_BOSSES,
+        }
+    }
+
+    /// Enemy health multiplier on top of the difficulty
+    pub fn enemy_health(&self, seconds: f32, is_boss: bool, bosses: &BossProgress) -> f32 {
+        match self {
+            GameMode::Classic | GameMode::Daily => 1.0,
+            // 无尽模式每十分钟敌人血量增加一倍初始血量（线性增长）
+            GameMode::Endless => 1.0 + seconds / 600.0,
+            GameMode::BossRush if is_boss => 1.0 + 0.5 * bosses.spawned.saturating_sub(1) as f32,
+            GameMode::BossRush => 1.0,
+        }
+    }
+}
+
+impl LocalizeKey for GameMode {
+    fn localize_key(&self) -> &'static str {
+        match self {
+            GameMode::Classic => "mode.classic",
+            GameMode::Endless => "mode.endless",
+            GameMode::BossRush => "mode.boss_rush",
+            GameMode::Daily => "mode.daily",
+        }
+    }
+}
+
+/// Bosses of the current run
+#[derive(Resource, Debug, Default)]
+pub struct BossProgress {
+    pub spawned: u32,
+    pub defeated: u32,
+    /// Game time of the last boss kill
+    pub last_defeat_seconds: f32,
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<GameMode>()
+        .init_resource::<BossProgress>()
+        .add_systems(OnEnter(AppStates::Game), reset_boss_progress)
+        .add_systems(
+            Update,
+            track_bosses
+                .run_if(in_state(GameStates::Playing))
+                .run_if(in_state(AppStates::Game)),
+        );
+}
+
+fn reset_boss_progress(mut bosses: ResMut<BossProgress>) {
+    *bosses = BossProgress::default();
+}
+
+pub fn track_bosses(
+    mut mob_destroyed_events: EventReader<MobDestroyedEvent>,
+    mut bosses: ResMut<BossProgress>,
+    game_time: Res<GameTime>,
+) {
+    for ev in mob_destroyed_events.read() {
+        if ev.is_boss {
+            bosses.defeated += 1;
+            bosses.last_defeat_seconds = game_time.0.elapsed_secs();
+        }
+    }
+}
@@ -3,13 +3,15 @@ use crate::components::character::CharacterType;
 use crate::{
     components::health::{Health, Spacecraft},
     gameplay::{
+        daily::RunModifiers,
         gamelogic::{game_not_paused, Allegiance, PlayerLevel, Targettable, WillTarget},
+        hangar::Hangar,
         loot::{Cargo, Magnet},
         physics::{BaseRotation, Collider, Physics},
         GameStates,
     },
     screens::AppStates,
-    ship::engine::Engine,
+    ship::{engine::Engine, turret::FireWeaponEvent},
     util::RenderLayer,
     AppSet, CameraShake, MainCamera,
 };
@@ -39,7 +41,7 @@ pub(super) fn plugin(app: &mut App) {
     );
     app.add_systems(
         Update,
-        (player_control, level_up_system)
+        (player_control, fire_control, level_up_system)
             .chain()
             .in_set(AppSet::Update)
             .distributive_run_if(game_not_paused)
@@ -106,7 +108,11 @@ fn spawn_player(
     In(config): In<SpawnPlayer>,
     mut commands: Commands,
     player_assets: Res<PlayerAssets>,
+    hangar: Res<Hangar>,
+    modifiers: Res<RunModifiers>,
 ) {
+    let magnet = Magnet::default();
+    let shields = (config.max_shield + hangar.bonus_shields()) as f32 * modifiers.player_shields();
     commands
         .spawn((
             Spacecraft,
@@ -118,7 +124,7 @@ fn spawn_player(
             }),
             Physics::new(config.drag),
             Engine::new_with_steering(config.power, config.max_speed, config.steering_factor),
-            Health::new(config.max_health, config.max_shield, 2.0),
+            Health::new(config.max_health, shields.round() as usize, 2.0),
             Collider {
                 radius: config.radius,
             },
@@ -130,7 +136,10 @@ fn spawn_player(
             Name::new("Player"),
             PlayerComponent,
             Cargo::default(),
-            Magnet::default(),
+            Magnet {
+                range: magnet.range * hangar.magnet_range_multiplier(),
+                ..magnet
+            },
         ))
         .insert(StateScoped(AppStates::Game));
     info!("Player spawned");
@@ -181,13 +190,36 @@ pub fn player_control(
     }
 }
 
+/// Fires the manual turrets of the player, automatic ones ignore it
+pub fn fire_control(
+    key_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    query: Query<Entity, With<PlayerComponent>>,
+    mut fire_event: EventWriter<FireWeaponEvent>,
+) {
+    if key_input.pressed(KeyCode::KeyF)
+        || gamepads
+            .iter()
+            .any(|gamepad| gamepad.pressed(GamepadButton::RightTrigger))
+    {
+        for ship in &query {
+            fire_event.send(FireWeaponEvent { ship });
+        }
+    }
+}
+
 pub fn pause_control(
     key_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
     game_state: Res<State<GameStates>>,
     mut change_game_state: ResMut<NextState<GameStates>>,
     mut query: Query<&mut CameraShake>,
 ) {
-    if key_input.just_pressed(KeyCode::Space) {
+    if key_input.any_just_pressed([KeyCode::Space, KeyCode::Escape])
+        || gamepads
+            .iter()
+            .any(|gamepad| gamepad.just_pressed(GamepadButton::Start))
+    {
         match game_state.get() {
             GameStates::Playing => change_game_state.set(GameStates::Paused),
             GameStates::Paused => change_game_state.set(GameStates::Playing),
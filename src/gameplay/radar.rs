@@ -0,0 +1,224 @@
+//! Radar in the bottom left corner of the HUD, plotting what is around the player well beyond
+//! the edges of the screen.
+use crate::components::spawnable::MobComponent;
+use crate::enemy::director::DirectorTable;
+use crate::enemy::{FinalBoss, AI};
+use crate::gameplay::loot::IsLoot;
+use crate::gameplay::object::SpaceObject;
+use crate::gameplay::player::PlayerComponent;
+use crate::screens::AppStates;
+use crate::util::Colour;
+use bevy::prelude::*;
+
+/// Width and height of the radar on screen
+const RADAR_SIZE: f32 = 160.0;
+
+/// World distance covered from the centre to the edge of the radar
+const RADAR_RANGE: f32 = 1500.0;
+
+/// Range rings, as fractions of the radar range
+const RANGE_RINGS: [f32; 3] = [1.0 / 3.0, 2.0 / 3.0, 1.0];
+
+/// Blips shown at once, bosses are kept over everything else
+const MAX_BLIPS: usize = 96;
+
+/// Kinds of things shown on the radar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Blip {
+    Enemy,
+    /// Bosses and elites, kept on the rim of the radar when out of range
+    Boss,
+    Loot,
+    Object,
+}
+
+impl Blip {
+    fn colour(&self) -> Color {
+        match self {
+            Blip::Enemy => Colour::ENEMY,
+            Blip::Boss => Colour::RED,
+            Blip::Loot => Colour::YELLOW,
+            Blip::Object => Colour::INACTIVE,
+        }
+    }
+
+    fn size(&self) -> f32 {
+        match self {
+            Blip::Enemy => 4.0,
+            Blip::Boss => 8.0,
+            Blip::Loot => 3.0,
+            Blip::Object => 6.0,
+        }
+    }
+
+    /// Loot and space objects are round, ships are square
+    fn round(&self) -> bool {
+        matches!(self, Blip::Loot | Blip::Object)
+    }
+}
+
+/// Container of the pool of blips, in drawing order
+#[derive(Component)]
+struct RadarBlips;
+
+#[derive(Component)]
+struct RadarBlip;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(AppStates::Game), setup_radar)
+        .add_systems(Update, radar_system.run_if(in_state(AppStates::Game)));
+}
+
+fn setup_radar(mut commands: Commands) {
+    commands
+        .spawn((
+            Name::new("Radar"),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(5.0),
+                bottom: Val::Px(5.0),
+                width: Val::Px(RADAR_SIZE),
+                height: Val::Px(RADAR_SIZE),
+                ..default()
+            },
+            BackgroundColor(Colour::BLACK.with_alpha(0.6)),
+            BorderRadius::MAX,
+            StateScoped(AppStates::Game),
+        ))
+        .with_children(|parent| {
+            for ring in RANGE_RINGS {
+                let size = RADAR_SIZE * ring;
+                parent.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px((RADAR_SIZE - size) / 2.0),
+                        top: Val::Px((RADAR_SIZE - size) / 2.0),
+                        width: Val::Px(size),
+                        height: Val::Px(size),
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor(Colour::INACTIVE.with_alpha(0.5)),
+                    BorderRadius::MAX,
+                ));
+            }
+            parent
+                .spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    RadarBlips,
+                ))
+                .with_children(|blips| {
+                    for _ in 0..MAX_BLIPS {
+                        blips.spawn((
+                            blip_node(Vec2::ZERO, Colour::ENEMY, 0.0, false),
+                            RadarBlip,
+                            Visibility::Hidden,
+                        ));
+                    }
+                });
+            // 玩家始终在雷达中心
+            parent.spawn(blip_node(Vec2::ZERO, Colour::PLAYER, 6.0, false));
+        });
+}
+
+/// Node of a blip, `offset` runs from -1 to 1 across the radar with y pointing up
+fn blip_node(offset: Vec2, colour: Color, size: f32, round: bool) -> impl Bundle {
+    let mut node = Node {
+        position_type: PositionType::Absolute,
+        ..default()
+    };
+    place_blip(&mut node, offset, size);
+    (node, BackgroundColor(colour), blip_radius(round))
+}
+
+fn place_blip(node: &mut Node, offset: Vec2, size: f32) {
+    let radius = RADAR_SIZE / 2.0;
+    node.left = Val::Px(radius + offset.x * radius - size / 2.0);
+    node.top = Val::Px(radius - offset.y * radius - size / 2.0);
+    node.width = Val::Px(size);
+    node.height = Val::Px(size);
+}
+
+fn blip_radius(round: bool) -> BorderRadius {
+    if round {
+        BorderRadius::MAX
+    } else {
+        BorderRadius::ZERO
+    }
+}
+
+fn radar_system(
+    table: Res<DirectorTable>,
+    container: Query<&Children, With<RadarBlips>>,
+    mut blips: Query<
+        (
+            &mut Node,
+            &mut BackgroundColor,
+            &mut BorderRadius,
+            &mut Visibility,
+        ),
+        With<RadarBlip>,
+    >,
+    player: Query<&Transform, With<PlayerComponent>>,
+    enemies: Query<(&Transform, Option<&MobComponent>, Has<FinalBoss>), With<AI>>,
+    loot: Query<&Transform, With<IsLoot>>,
+    objects: Query<&Transform, With<SpaceObject>>,
+) {
+    let Ok(children) = container.get_single() else {
+        return;
+    };
+    let Ok(player) = player.get_single() else {
+        return;
+    };
+    let centre = player.translation.truncate();
+
+    let enemies = enemies.iter().map(|(transform, mob, final_boss)| {
+        let elite = mob.is_some_and(|mob| table.is_boss_or_elite(&mob.mob_type));
+        let blip = if final_boss || elite {
+            Blip::Boss
+        } else {
+            Blip::Enemy
+        };
+        (transform, blip)
+    });
+    let mut shown: Vec<(Vec2, Blip)> = objects
+        .iter()
+        .map(|transform| (transform, Blip::Object))
+        .chain(loot.iter().map(|transform| (transform, Blip::Loot)))
+        .chain(enemies)
+        .filter_map(|(transform, blip)| {
+            let offset = (transform.translation.truncate() - centre) / RADAR_RANGE;
+            if offset.length() <= 1.0 {
+                Some((offset, blip))
+            } else if blip == Blip::Boss {
+                Some((offset.normalize(), blip))
+            } else {
+                None
+            }
+        })
+        .collect();
+    // 首领最后绘制，保证显示在最上层，超出数量时先舍弃普通目标
+    shown.sort_by_key(|(_, blip)| *blip == Blip::Boss);
+    let excess = shown.len().saturating_sub(MAX_BLIPS);
+    shown.drain(..excess);
+
+    let mut shown = shown.into_iter();
+    for &child in children.iter() {
+        let Ok((mut node, mut colour, mut radius, mut visibility)) = blips.get_mut(child) else {
+            continue;
+        };
+        let Some((offset, blip)) = shown.next() else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        place_blip(&mut node, offset, blip.size());
+        colour.set_if_neq(BackgroundColor(blip.colour()));
+        radius.set_if_neq(blip_radius(blip.round()));
+        visibility.set_if_neq(Visibility::Inherited);
+    }
+}
@@ -0,0 +1,31 @@
+//! Seeded random numbers for what shapes a run: enemy waves, upgrade offers and loot drops.
+//! Cosmetic randomness keeps using `rand::thread_rng`.
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[derive(Resource)]
+pub struct RunRng {
+    /// Seed the run started from, shown to players comparing daily challenges
+    pub seed: u64,
+    pub rng: StdRng,
+}
+
+impl RunRng {
+    pub fn new(seed: u64) -> Self {
+        RunRng {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Default for RunRng {
+    fn default() -> Self {
+        RunRng::new(rand::random())
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<RunRng>();
+}
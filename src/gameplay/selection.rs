@@ -1,11 +1,16 @@
 use crate::assets::audio_assets::Fonts;
 use crate::gameplay::gamelogic::PlayerLevel;
+use crate::gameplay::hangar::{Hangar, HangarUpgrade};
+use crate::gameplay::rng::RunRng;
 use crate::gameplay::upgrade::{PlayerUpgrades, UpgradeEvent};
 use crate::gameplay::GameStates;
+use crate::screens::AppStates;
 use crate::ship::turret::TurretClass;
-use crate::theme::language::Localize;
+use crate::theme::language::{Localize, LocalizeFont};
 use crate::util::Colour;
 use bevy::prelude::*;
+use rand::distr::Standard;
+use rand::seq::SliceRandom;
 use rand::Rng;
 
 #[derive(Resource)]
@@ -14,37 +19,57 @@ struct SelectionData(pub Vec<Entity>);
 #[derive(Component)]
 struct SelectionButton(UpgradeEvent);
 
+#[derive(Component)]
+struct RerollButton;
+
+/// Rerolls of the level up options left in this run
+#[derive(Resource, Default)]
+pub struct Rerolls(pub u8);
+
 const HOVERED_BUTTON: Color = Color::srgb(0.25, 0.25, 0.25);
 const NORMAL_BUTTON: Color = Color::srgb(0.15, 0.15, 0.15);
 
 pub(super) fn plugin(app: &mut App) {
     app.insert_resource(SelectionData(vec![]))
+        .init_resource::<Rerolls>()
+        .add_systems(OnEnter(AppStates::Game), reset_rerolls)
         .add_systems(OnEnter(GameStates::Selection), setup_selection)
-        .add_systems(Update, menu.run_if(in_state(GameStates::Selection)))
+        .add_systems(
+            Update,
+            (menu, reroll_menu).distributive_run_if(in_state(GameStates::Selection)),
+        )
         .add_systems(OnExit(GameStates::Selection), cleanup);
 }
 
-fn random_starting_weapon() -> TurretClass {
-    match rand::thread_rng().gen_range(0..4) {
-        0 => TurretClass::AutoCannon,
-        1 => TurretClass::BlastLaser,
-        2 => TurretClass::RocketLauncher,
-        _ => TurretClass::MineLauncher,
-    }
+fn reset_rerolls(mut rerolls: ResMut<Rerolls>, hangar: Res<Hangar>) {
+    rerolls.0 = hangar.rerolls();
 }
 
-fn roll_starting() -> Vec<UpgradeEvent> {
-    let mut options: Vec<UpgradeEvent> = vec![];
-    while options.len() < 3 {
-        let potential = UpgradeEvent::Weapon(random_starting_weapon());
-        if !options.contains(&potential) {
-            options.push(potential);
-        }
+/// Weapons offered at level 1, widened by the hangar's weapon choice upgrade
+fn starting_weapons(choice_level: u8) -> Vec<TurretClass> {
+    let mut weapons = vec![
+        TurretClass::AutoCannon,
+        TurretClass::BlastLaser,
+        TurretClass::RocketLauncher,
+        TurretClass::MineLauncher,
+    ];
+    if choice_level > 0 {
+        weapons.extend([TurretClass::ShrapnelCannon, TurretClass::ChainLaser]);
     }
-    options
+    weapons
 }
 
-fn roll(upgrades: Res<PlayerUpgrades>) -> Vec<UpgradeEvent> {
+fn roll_starting(choice_level: u8, rng: &mut impl Rng) -> Vec<UpgradeEvent> {
+    let mut weapons = starting_weapons(choice_level);
+    weapons.shuffle(rng);
+    weapons
+        .into_iter()
+        .take(3 + choice_level as usize)
+        .map(UpgradeEvent::Weapon)
+        .collect()
+}
+
+fn roll(upgrades: &PlayerUpgrades, rng: &mut impl Rng) -> Vec<UpgradeEvent> {
     let mut options: Vec<UpgradeEvent> = vec![];
     let mut iterations = 0;
     while options.len() < 3 {
@@ -55,7 +80,7 @@ fn roll(upgrades: Res<PlayerUpgrades>) -> Vec<UpgradeEvent> {
             continue;
         }
 
-        let potential: UpgradeEvent = rand::random();
+        let potential: UpgradeEvent = rng.sample(Standard);
         // No duplicates
         if options.contains(&potential) {
             continue;
@@ -84,20 +109,49 @@ fn roll(upgrades: Res<PlayerUpgrades>) -> Vec<UpgradeEvent> {
     options
 }
 
+fn roll_options(
+    level: &PlayerLevel,
+    upgrades: &PlayerUpgrades,
+    hangar: &Hangar,
+    rng: &mut impl Rng,
+) -> Vec<UpgradeEvent> {
+    match level.value {
+        1 => roll_starting(hangar.level(HangarUpgrade::WeaponChoice), rng),
+        _ => roll(upgrades, rng),
+    }
+}
+
 fn setup_selection(
     mut commands: Commands,
     fonts: Res<Fonts>,
     mut menu_data: ResMut<SelectionData>,
     player_level: Res<PlayerLevel>,
     upgrades: Res<PlayerUpgrades>,
+    hangar: Res<Hangar>,
+    rerolls: Res<Rerolls>,
     localize: Res<Localize>,
+    mut rng: ResMut<RunRng>,
 ) {
     // Roll for options
-    let options = match player_level.value {
-        1 => roll_starting(),
-        _ => roll(upgrades),
-    };
+    let options = roll_options(&player_level, &upgrades, &hangar, &mut rng.rng);
+    spawn_options(
+        &mut commands,
+        &fonts,
+        &mut menu_data,
+        options,
+        rerolls.0,
+        &localize,
+    );
+}
 
+fn spawn_options(
+    commands: &mut Commands,
+    fonts: &Res<Fonts>,
+    menu_data: &mut SelectionData,
+    options: Vec<UpgradeEvent>,
+    rerolls: u8,
+    localize: &Res<Localize>,
+) {
     let root_entity = commands
         .spawn(Node {
             width: Val::Percent(100.0),
@@ -105,13 +159,46 @@ fn setup_selection(
             position_type: PositionType::Absolute,
             justify_content: JustifyContent::Center,
             align_items: AlignItems::Center,
-            flex_direction: FlexDirection::Row,
-            column_gap: Val::Px(10.0),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(20.0),
             ..default()
         })
         .with_children(|parent| {
-            for option in options {
-                button(parent, &fonts, option, &localize);
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(10.0),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for option in options {
+                        button(parent, fonts, option, localize);
+                    }
+                });
+            if rerolls > 0 {
+                parent
+                    .spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(200.0),
+                            height: Val::Px(50.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(NORMAL_BUTTON),
+                        RerollButton,
+                    ))
+                    .with_child((
+                        Text::new(localize.format("selection.reroll", &[("count", &rerolls)])),
+                        TextFont {
+                            font: fonts.primary.clone(),
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor::from(Colour::YELLOW),
+                        LocalizeFont,
+                    ));
             }
         })
         .id();
@@ -142,7 +229,53 @@ fn menu(
     }
 }
 
+/// Replaces the options with new ones while rerolls are left
+fn reroll_menu(
+    mut commands: Commands,
+    fonts: Res<Fonts>,
+    mut menu_data: ResMut<SelectionData>,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<Button>, With<RerollButton>),
+    >,
+    player_level: Res<PlayerLevel>,
+    upgrades: Res<PlayerUpgrades>,
+    hangar: Res<Hangar>,
+    mut rerolls: ResMut<Rerolls>,
+    localize: Res<Localize>,
+    mut rng: ResMut<RunRng>,
+) {
+    for (interaction, mut color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed if rerolls.0 > 0 => {
+                rerolls.0 -= 1;
+                despawn_menu(&mut commands, &mut menu_data);
+                let options = roll_options(&player_level, &upgrades, &hangar, &mut rng.rng);
+                spawn_options(
+                    &mut commands,
+                    &fonts,
+                    &mut menu_data,
+                    options,
+                    rerolls.0,
+                    &localize,
+                );
+                return;
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+            }
+            _ => {
+                *color = NORMAL_BUTTON.into();
+            }
+        }
+    }
+}
+
 fn cleanup(mut commands: Commands, mut menu_data: ResMut<SelectionData>) {
+    despawn_menu(&mut commands, &mut menu_data);
+}
+
+fn despawn_menu(commands: &mut Commands, menu_data: &mut SelectionData) {
     for entity in menu_data.0.iter() {
         if let Some(entity) = commands.get_entity(*entity) {
             entity.despawn_recursive();
@@ -192,6 +325,7 @@ fn button(
                     ..default()
                 },
                 TextColor::from(type_color),
+                LocalizeFont,
                 Node {
                     top: Val::Px(10.0),
                     position_type: PositionType::Absolute,
@@ -199,13 +333,14 @@ fn button(
                 },
             ));
             parent.spawn((
-                Text::new(localize.get(format!("{}", upgrade).as_str())),
+                Text::new(localize.get_key(&upgrade)),
                 TextFont {
                     font: fonts.primary.clone(),
                     font_size: 24.0,
                     ..default()
                 },
                 TextColor::from(Color::srgb(0.9, 0.9, 0.9)),
+                LocalizeFont,
                 Node {
                     top: Val::Px(30.0),
                     position_type: PositionType::Absolute,
@@ -220,6 +355,7 @@ fn button(
                     ..default()
                 },
                 TextColor::from(Color::srgba(0.8, 0.8, 0.8, 0.8)),
+                LocalizeFont,
                 TextLayout {
                     justify: JustifyText::Center,
                     ..default()
@@ -0,0 +1,147 @@
+//! Statistics of the current run, shown on the game over screen.
+use crate::components::health::Health;
+use crate::components::spawnable::{MobDestroyedEvent, MobType};
+use crate::gameplay::gamelogic::{take_damage_events, GameTime, TakeDamageEvent};
+use crate::gameplay::loot::LootCollectedEvent;
+use crate::gameplay::player::PlayerComponent;
+use crate::gameplay::upgrade::UpgradeEvent;
+use crate::screens::AppStates;
+use crate::ship::turret::{TurretClass, TurretFireEvent};
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+#[derive(Resource, Default)]
+pub struct RunStatistics {
+    /// Damage dealt to enemies by each of the player's weapons
+    pub damage_dealt: HashMap<TurretClass, usize>,
+    pub shots_fired: HashMap<TurretClass, usize>,
+    pub kills: HashMap<MobType, usize>,
+    pub damage_taken: usize,
+    /// Part of `damage_taken` that hit the shields instead of the armor
+    pub shields_absorbed: usize,
+    pub loot_collected: usize,
+    /// Upgrades picked, with the game time in seconds they were picked at
+    pub upgrades: Vec<(u64, UpgradeEvent)>,
+}
+
+impl RunStatistics {
+    /// Weapons by damage dealt, highest first
+    pub fn damage_by_weapon(&self) -> Vec<(TurretClass, usize)> {
+        let mut damage = self
+            .damage_dealt
+            .iter()
+            .map(|(class, amount)| (*class, *amount))
+            .collect::<Vec<_>>();
+        damage.sort_by(|a, b| b.1.cmp(&a.1));
+        damage
+    }
+
+    /// Enemies by number destroyed, highest first
+    pub fn kills_by_mob(&self) -> Vec<(MobType, usize)> {
+        let mut kills = self
+            .kills
+            .iter()
+            .map(|(mob_type, count)| (mob_type.clone(), *count))
+            .collect::<Vec<_>>();
+        kills.sort_by(|a, b| b.1.cmp(&a.1));
+        kills
+    }
+
+    pub fn total_kills(&self) -> usize {
+        self.kills.values().sum()
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<RunStatistics>()
+        .add_systems(OnEnter(AppStates::Game), reset_statistics)
+        .add_systems(
+            Update,
+            (
+                record_damage.before(take_damage_events),
+                record_shots,
+                record_kills,
+                record_loot,
+                record_upgrades,
+            )
+                .distributive_run_if(in_state(AppStates::Game)),
+        );
+}
+
+fn reset_statistics(mut statistics: ResMut<RunStatistics>) {
+    *statistics = RunStatistics::default();
+}
+
+/// Runs before the damage is applied, so the player's shields still tell how much they absorb
+fn record_damage(
+    mut take_damage_events: EventReader<TakeDamageEvent>,
+    mut statistics: ResMut<RunStatistics>,
+    player: Query<(Entity, &Health), With<PlayerComponent>>,
+) {
+    let player = player.get_single().ok();
+    let player_entity = player.map(|(entity, _)| entity);
+    // 同一帧可能有多次伤害，护盾按顺序扣减
+    let mut shields = player.map(|(_, health)| health.shields).unwrap_or_default();
+    for ev in take_damage_events.read() {
+        if player_entity == Some(ev.entity) {
+            let absorbed = ev.damage.amount.min(shields);
+            shields -= absorbed;
+            statistics.damage_taken += ev.damage.amount;
+            statistics.shields_absorbed += absorbed;
+        } else if let Some(source) = ev
+            .source
+            .filter(|source| player_entity == Some(source.ship))
+        {
+            // 敌人的武器和爆炸不算玩家造成的伤害
+            *statistics.damage_dealt.entry(source.class).or_insert(0) += ev.damage.amount;
+        }
+    }
+}
+
+fn record_shots(
+    mut fire_events: EventReader<TurretFireEvent>,
+    mut statistics: ResMut<RunStatistics>,
+    turrets: Query<&Parent>,
+    player: Query<(), With<PlayerComponent>>,
+) {
+    for ev in fire_events.read() {
+        let fired_by_player = turrets
+            .get(ev.turret)
+            .is_ok_and(|parent| player.contains(parent.get()));
+        if fired_by_player {
+            *statistics.shots_fired.entry(ev.class).or_insert(0) += 1;
+        }
+    }
+}
+
+fn record_kills(
+    mut mob_destroyed_events: EventReader<MobDestroyedEvent>,
+    mut statistics: ResMut<RunStatistics>,
+) {
+    for ev in mob_destroyed_events.read() {
+        if let MobType::Enemy(_) = ev.mob_type {
+            *statistics.kills.entry(ev.mob_type.clone()).or_insert(0) += 1;
+        }
+    }
+}
+
+fn record_loot(
+    mut loot_events: EventReader<LootCollectedEvent>,
+    mut statistics: ResMut<RunStatistics>,
+) {
+    for ev in loot_events.read() {
+        statistics.loot_collected += ev.cargo;
+    }
+}
+
+fn record_upgrades(
+    mut upgrade_events: EventReader<UpgradeEvent>,
+    mut statistics: ResMut<RunStatistics>,
+    game_time: Res<GameTime>,
+) {
+    for ev in upgrade_events.read() {
+        statistics
+            .upgrades
+            .push((game_time.0.elapsed().as_secs(), *ev));
+    }
+}
@@ -4,7 +4,7 @@ use crate::gameplay::player::PlayerComponent;
 use crate::screens::AppStates;
 use crate::ship::engine::Engine;
 use crate::ship::turret::{DoesDamage, EffectSize, FireRate, MultiShot, TurretBundle, TurretClass};
-use crate::theme::prelude::Localize;
+use crate::theme::prelude::{Localize, LocalizeKey};
 use bevy::app::App;
 use bevy::prelude::*;
 use bevy::utils::HashMap;
@@ -22,12 +22,17 @@ impl PlayerUpgrades {
         self.0
             .iter()
             .filter(|(_, level)| **level > 0)
-            .map(|(upgrade, level)| {
-                format!(
-                    "{:0>2} {:>16}",
-                    level,
-                    localize.get(format!("{}", upgrade).as_str())
-                )
+            .map(|(upgrade, level)| format!("{:0>2} {:>16}", level, localize.get_key(upgrade)))
+            .collect()
+    }
+
+    /// Weapons picked so far
+    pub fn weapons(&self) -> Vec<TurretClass> {
+        self.0
+            .keys()
+            .filter_map(|upgrade| match upgrade {
+                UpgradeEvent::Weapon(weapon) => Some(*weapon),
+                _ => None,
             })
             .collect()
     }
@@ -63,8 +68,8 @@ pub enum UpgradeEvent {
 impl Distribution<UpgradeEvent> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> UpgradeEvent {
         match rng.gen_range(0..2) {
-            0 => UpgradeEvent::Weapon(rand::random()),
-            _ => UpgradeEvent::Passive(rand::random()),
+            0 => UpgradeEvent::Weapon(rng.sample(Standard)),
+            _ => UpgradeEvent::Passive(rng.sample(Standard)),
         }
     }
 }
@@ -79,27 +84,20 @@ impl Display for UpgradeEvent {
     }
 }
 
-impl UpgradeEvent {
-    pub fn describe(&self) -> String {
+impl LocalizeKey for UpgradeEvent {
+    fn localize_key(&self) -> &'static str {
         match self {
-            UpgradeEvent::Weapon(TurretClass::AutoCannon) => "AutoCannonDes",
-            UpgradeEvent::Weapon(TurretClass::BlastLaser) => "BlastLaserDes",
-            UpgradeEvent::Weapon(TurretClass::ChainLaser) => "ChainLaserDes",
-            UpgradeEvent::Weapon(TurretClass::Emp) => "EmpDes",
-            UpgradeEvent::Weapon(TurretClass::MineLauncher) => "MineLauncherDes",
-            UpgradeEvent::Weapon(TurretClass::PierceLaser) => "PierceLaserDes",
-            UpgradeEvent::Weapon(TurretClass::RocketLauncher) => "RocketLauncherDes",
-            UpgradeEvent::Weapon(TurretClass::ShrapnelCannon) => "ShrapnelCannonDes",
-            UpgradeEvent::Passive(Passive::Armor) => "ArmorDes",
-            UpgradeEvent::Passive(Passive::Crit) => "CritDes",
-            UpgradeEvent::Passive(Passive::Experience) => "ExperienceDes",
-            UpgradeEvent::Passive(Passive::FireRate) => "FireRateDes",
-            UpgradeEvent::Passive(Passive::Magnet) => "MagnetDes",
-            UpgradeEvent::Passive(Passive::ShieldRecharge) => "ShieldRechargeDes",
-            UpgradeEvent::Passive(Passive::Speed) => "SpeedDes",
-            UpgradeEvent::Heal => "HealDes",
+            UpgradeEvent::Weapon(weapon) => weapon.localize_key(),
+            UpgradeEvent::Passive(passive) => passive.localize_key(),
+            UpgradeEvent::Heal => "upgrade.heal",
         }
-        .to_string()
+    }
+}
+
+impl UpgradeEvent {
+    /// Keyword of the upgrade's description
+    pub fn describe(&self) -> String {
+        format!("{}.desc", self.localize_key())
     }
 }
 
@@ -128,6 +126,20 @@ impl Display for Passive {
     }
 }
 
+impl LocalizeKey for Passive {
+    fn localize_key(&self) -> &'static str {
+        match self {
+            Passive::Speed => "passive.speed",
+            Passive::Magnet => "passive.magnet",
+            Passive::ShieldRecharge => "passive.shield_recharge",
+            Passive::Armor => "passive.armor",
+            Passive::FireRate => "passive.fire_rate",
+            Passive::Crit => "passive.crit",
+            Passive::Experience => "passive.experience",
+        }
+    }
+}
+
 impl Distribution<Passive> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Passive {
         match rng.gen_range(0..7) {
@@ -145,6 +157,7 @@ impl Distribution<Passive> for Standard {
 pub(super) fn plugin(app: &mut App) {
     app.insert_resource(PlayerUpgrades(HashMap::new()))
         .add_event::<UpgradeEvent>()
+        .add_systems(OnEnter(AppStates::Game), reset_upgrades)
         .add_systems(
             Update,
             (
@@ -161,6 +174,10 @@ pub(super) fn plugin(app: &mut App) {
         );
 }
 
+fn reset_upgrades(mut player_upgrades: ResMut<PlayerUpgrades>) {
+    player_upgrades.0.clear();
+}
+
 fn record_upgrade(
     mut upgrade_event: EventReader<UpgradeEvent>,
     mut player_upgrades: ResMut<PlayerUpgrades>,
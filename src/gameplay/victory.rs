@@ -0,0 +1,85 @@
+//! Winning a run by destroying the last boss the game mode asks for.
+use crate::components::spawnable::MobDestroyedEvent;
+use crate::gameplay::loot::Points;
+use crate::gameplay::mode::{track_bosses, BossProgress, GameMode};
+use crate::gameplay::GameStates;
+use crate::screens::AppStates;
+use bevy::prelude::*;
+
+/// Points awarded for destroying the final boss
+pub const VICTORY_BONUS: u32 = 1000;
+
+/// Game speed right after the final boss dies
+const SLOW_MOTION_SPEED: f32 = 0.2;
+
+/// Real time the slow motion lasts before the victory screen
+const SLOW_MOTION_SECONDS: f32 = 2.0;
+
+/// Slow motion following the final boss kill, ticks in real time
+#[derive(Resource)]
+struct SlowMotion(Timer);
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(AppStates::Game), reset_victory)
+        .add_systems(OnExit(AppStates::Game), reset_game_speed)
+        .add_systems(
+            Update,
+            (
+                start_slow_motion.after(track_bosses),
+                slow_motion_system.run_if(resource_exists::<SlowMotion>),
+            )
+                .chain()
+                .run_if(in_state(AppStates::Game)),
+        )
+        .add_systems(OnEnter(GameStates::Victory), award_victory_bonus);
+}
+
+fn reset_victory(mut commands: Commands) {
+    commands.remove_resource::<SlowMotion>();
+}
+
+fn reset_game_speed(mut commands: Commands, mut time: ResMut<Time<Virtual>>) {
+    commands.remove_resource::<SlowMotion>();
+    time.set_relative_speed(1.0);
+}
+
+fn start_slow_motion(
+    mut commands: Commands,
+    mut mob_destroyed_events: EventReader<MobDestroyedEvent>,
+    mut time: ResMut<Time<Virtual>>,
+    mode: Res<GameMode>,
+    bosses: Res<BossProgress>,
+) {
+    for ev in mob_destroyed_events.read() {
+        if ev.is_boss && mode.is_won(bosses.defeated) {
+            time.set_relative_speed(SLOW_MOTION_SPEED);
+            commands.insert_resource(SlowMotion(Timer::from_seconds(
+                SLOW_MOTION_SECONDS,
+                TimerMode::Once,
+            )));
+        }
+    }
+}
+
+fn slow_motion_system(
+    mut commands: Commands,
+    real_time: Res<Time<Real>>,
+    mut time: ResMut<Time<Virtual>>,
+    mut slow_motion: ResMut<SlowMotion>,
+    game_state: Res<State<GameStates>>,
+    mut next_state: ResMut<NextState<GameStates>>,
+) {
+    if !slow_motion.0.tick(real_time.delta()).finished() {
+        return;
+    }
+    time.set_relative_speed(1.0);
+    commands.remove_resource::<SlowMotion>();
+    // 慢动作期间玩家阵亡则不算胜利
+    if *game_state.get() != GameStates::GameOver {
+        next_state.set(GameStates::Victory);
+    }
+}
+
+pub fn award_victory_bonus(mut points: ResMut<Points>) {
+    points.value += VICTORY_BONUS;
+}
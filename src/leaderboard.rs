@@ -0,0 +1,335 @@
+//! Optional global leaderboard. Runs are posted to and rankings fetched from an HTTP/JSON server
+//! when the `online` feature is enabled and `GameConfig.online` is set. Without a server the
+//! high-score screen falls back to the local table.
+use crate::config::GameConfig;
+use crate::gameplay::daily::DailyScored;
+use crate::gameplay::gamelogic::GameTime;
+use crate::gameplay::highscore::{commit_pending_high_score, DEFAULT_PLAYER_NAME};
+use crate::gameplay::loot::Points;
+use crate::gameplay::mode::GameMode;
+use crate::gameplay::rng::RunRng;
+use crate::gameplay::GameStates;
+use crate::screens::AppStates;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+use thiserror::Error;
+
+/// Number of global rankings fetched for the high-score screen
+pub const RANKINGS_LIMIT: usize = 10;
+
+/// Server used by online builds, override at compile time with `LEADERBOARD_URL`
+#[cfg(feature = "online")]
+const LEADERBOARD_URL: Option<&str> = option_env!("LEADERBOARD_URL");
+
+/// A finished run as posted to the leaderboard
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RunSubmission {
+    pub name: String,
+    pub score: u32,
+    /// Runs are ranked against runs of the same mode only
+    #[serde(default)]
+    pub mode: GameMode,
+    pub seed: Option<u64>,
+    /// Survival time in seconds
+    pub seconds: u64,
+    /// Game version that played the run
+    pub build: String,
+}
+
+/// A line of the global rankings
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    pub rank: usize,
+    pub name: String,
+    pub score: u32,
+    pub seconds: u64,
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum LeaderboardError {
+    #[error("leaderboard is offline")]
+    Offline,
+    #[error("leaderboard request failed: {0}")]
+    Request(String),
+    #[error("leaderboard sent an invalid response: {0}")]
+    InvalidResponse(String),
+}
+
+pub type SubmitCallback = Box<dyn FnOnce(Result<(), LeaderboardError>) + Send>;
+pub type FetchCallback = Box<dyn FnOnce(Result<Vec<LeaderboardEntry>, LeaderboardError>) + Send>;
+
+/// A leaderboard backend. Requests may complete on another thread, results are passed to the
+/// callbacks.
+pub trait LeaderboardClient: Send + Sync {
+    fn submit(&self, run: RunSubmission, done: SubmitCallback);
+    fn fetch(&self, mode: GameMode, limit: usize, done: FetchCallback);
+}
+
+/// Backend of builds without the `online` feature
+pub struct OfflineLeaderboard;
+
+impl LeaderboardClient for OfflineLeaderboard {
+    fn submit(&self, _run: RunSubmission, done: SubmitCallback) {
+        done(Err(LeaderboardError::Offline));
+    }
+
+    fn fetch(&self, _mode: GameMode, _limit: usize, done: FetchCallback) {
+        done(Err(LeaderboardError::Offline));
+    }
+}
+
+/// In-process stand-in for the leaderboard server, used by tests
+#[derive(Clone, Default)]
+pub struct LocalLeaderboard {
+    runs: Arc<Mutex<Vec<RunSubmission>>>,
+}
+
+impl LocalLeaderboard {
+    /// Every run submitted so far
+    pub fn runs(&self) -> Vec<RunSubmission> {
+        self.runs
+            .lock()
+            .map(|runs| runs.clone())
+            .unwrap_or_default()
+    }
+}
+
+impl LeaderboardClient for LocalLeaderboard {
+    fn submit(&self, run: RunSubmission, done: SubmitCallback) {
+        match self.runs.lock() {
+            Ok(mut runs) => {
+                runs.push(run);
+                done(Ok(()));
+            }
+            Err(err) => done(Err(LeaderboardError::Request(err.to_string()))),
+        }
+    }
+
+    fn fetch(&self, mode: GameMode, limit: usize, done: FetchCallback) {
+        let mut runs = self.runs();
+        runs.retain(|run| run.mode == mode);
+        runs.sort_by(|a, b| b.score.cmp(&a.score));
+        done(Ok(runs
+            .into_iter()
+            .take(limit)
+            .enumerate()
+            .map(|(index, run)| LeaderboardEntry {
+                rank: index + 1,
+                name: run.name,
+                score: run.score,
+                seconds: run.seconds,
+            })
+            .collect()));
+    }
+}
+
+/// Talks JSON to a leaderboard server: `POST {url}/runs` and
+/// `GET {url}/rankings?mode=Classic&limit=N`
+#[cfg(feature = "online")]
+pub struct HttpLeaderboard {
+    base_url: String,
+}
+
+#[cfg(feature = "online")]
+impl HttpLeaderboard {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+        }
+    }
+
+    /// `POST {url}/runs` with the run as a JSON body
+    pub fn submit_request(&self, run: &RunSubmission) -> Result<ehttp::Request, LeaderboardError> {
+        let body =
+            serde_json::to_vec(run).map_err(|err| LeaderboardError::Request(err.to_string()))?;
+        let mut request = ehttp::Request::post(format!("{}/runs", self.base_url), body);
+        request.headers.insert("Content-Type", "application/json");
+        Ok(request)
+    }
+
+    /// `GET {url}/rankings?mode=Classic&limit=N`
+    pub fn fetch_request(&self, mode: GameMode, limit: usize) -> ehttp::Request {
+        ehttp::Request::get(format!(
+            "{}/rankings?mode={:?}&limit={}",
+            self.base_url, mode, limit
+        ))
+    }
+
+    /// Reads the JSON array of entries answered to a rankings request
+    pub fn parse_rankings(bytes: &[u8]) -> Result<Vec<LeaderboardEntry>, LeaderboardError> {
+        serde_json::from_slice(bytes)
+            .map_err(|err| LeaderboardError::InvalidResponse(err.to_string()))
+    }
+}
+
+#[cfg(feature = "online")]
+impl LeaderboardClient for HttpLeaderboard {
+    fn submit(&self, run: RunSubmission, done: SubmitCallback) {
+        let request = match self.submit_request(&run) {
+            Ok(request) => request,
+            Err(err) => return done(Err(err)),
+        };
+        ehttp::fetch(request, move |result| {
+            done(checked_response(result).map(|_| ()));
+        });
+    }
+
+    fn fetch(&self, mode: GameMode, limit: usize, done: FetchCallback) {
+        ehttp::fetch(self.fetch_request(mode, limit), move |result| {
+            done(
+                checked_response(result)
+                    .and_then(|response| HttpLeaderboard::parse_rankings(&response.bytes)),
+            );
+        });
+    }
+}
+
+#[cfg(feature = "online")]
+fn checked_response(
+    result: ehttp::Result<ehttp::Response>,
+) -> Result<ehttp::Response, LeaderboardError> {
+    let response = result.map_err(LeaderboardError::Request)?;
+    if response.ok {
+        Ok(response)
+    } else {
+        Err(LeaderboardError::Request(format!(
+            "{} {}",
+            response.status, response.status_text
+        )))
+    }
+}
+
+/// State of the global rankings shown on the high-score screen
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Rankings {
+    #[default]
+    Idle,
+    Loading,
+    Global(Vec<LeaderboardEntry>),
+    /// The server could not be reached, only local scores are shown
+    Unavailable,
+}
+
+type FetchReplies = Arc<Mutex<Vec<Result<Vec<LeaderboardEntry>, LeaderboardError>>>>;
+
+#[derive(Resource)]
+pub struct Leaderboard {
+    client: Box<dyn LeaderboardClient>,
+    replies: FetchReplies,
+    pub rankings: Rankings,
+}
+
+impl Leaderboard {
+    pub fn new(client: Box<dyn LeaderboardClient>) -> Self {
+        Self {
+            client,
+            replies: default(),
+            rankings: Rankings::Idle,
+        }
+    }
+
+    /// Posts a run, failures are only logged
+    pub fn submit(&self, run: RunSubmission) {
+        self.client.submit(
+            run,
+            Box::new(|result| {
+                if let Err(err) = result {
+                    warn!("Failed to submit run: {}", err);
+                }
+            }),
+        );
+    }
+
+    /// Requests fresh rankings of a game mode, they arrive in a later `poll`
+    pub fn refresh(&mut self, mode: GameMode) {
+        self.rankings = Rankings::Loading;
+        let replies = self.replies.clone();
+        self.client.fetch(
+            mode,
+            RANKINGS_LIMIT,
+            Box::new(move |result| {
+                if let Ok(mut replies) = replies.lock() {
+                    replies.push(result);
+                }
+            }),
+        );
+    }
+
+    /// Rankings from the latest reply that arrived since the last call, if any
+    pub fn poll(&self) -> Option<Rankings> {
+        let reply = self.replies.lock().ok()?.drain(..).last()?;
+        Some(match reply {
+            Ok(entries) => Rankings::Global(entries),
+            Err(err) => {
+                warn!("Failed to fetch rankings: {}", err);
+                Rankings::Unavailable
+            }
+        })
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.insert_resource(Leaderboard::new(default_client()))
+        .add_systems(
+            OnExit(GameStates::GameOver),
+            submit_run
+                .after(commit_pending_high_score)
+                .run_if(online_enabled),
+        )
+        .add_systems(
+            Update,
+            poll_rankings.run_if(in_state(AppStates::HighScores)),
+        );
+}
+
+#[cfg(feature = "online")]
+fn default_client() -> Box<dyn LeaderboardClient> {
+    match LEADERBOARD_URL {
+        Some(url) => Box::new(HttpLeaderboard::new(url)),
+        // 没有配置服务器时和离线版本一样，高分榜只显示本地成绩
+        None => Box::new(OfflineLeaderboard),
+    }
+}
+
+#[cfg(not(feature = "online"))]
+fn default_client() -> Box<dyn LeaderboardClient> {
+    Box::new(OfflineLeaderboard)
+}
+
+fn online_enabled(config: Res<GameConfig>) -> bool {
+    config.online
+}
+
+fn submit_run(
+    leaderboard: Res<Leaderboard>,
+    config: Res<GameConfig>,
+    points: Res<Points>,
+    game_time: Res<GameTime>,
+    rng: Res<RunRng>,
+    mode: Res<GameMode>,
+    daily_scored: Res<DailyScored>,
+) {
+    if points.value == 0 || (*mode == GameMode::Daily && !daily_scored.0) {
+        return;
+    }
+    let name = if config.player_name.trim().is_empty() {
+        DEFAULT_PLAYER_NAME.to_string()
+    } else {
+        config.player_name.clone()
+    };
+    leaderboard.submit(RunSubmission {
+        name,
+        score: points.value,
+        mode: *mode,
+        seed: Some(rng.seed),
+        seconds: game_time.0.elapsed().as_secs(),
+        build: env!("CARGO_PKG_VERSION").to_string(),
+    });
+}
+
+fn poll_rankings(mut leaderboard: ResMut<Leaderboard>) {
+    if let Some(rankings) = leaderboard.poll() {
+        leaderboard.rankings = rankings;
+    }
+}
@@ -1,16 +1,18 @@
+pub mod achievement;
 pub mod audio;
 pub mod components;
 mod config;
 
 #[cfg(feature = "dev")]
 mod dev_tools;
-mod enemy;
-mod gameplay;
+pub mod enemy;
+pub mod gameplay;
+pub mod leaderboard;
 
 pub mod assets;
 mod screens;
-mod ship;
-mod theme;
+pub mod ship;
+pub mod theme;
 mod util;
 
 use crate::util::RenderLayer;
@@ -34,7 +36,8 @@ impl Plugin for AppPlugin {
             (AppSet::TickTimers, AppSet::RecordInput, AppSet::Update).chain(),
         )
         .insert_resource(AudioSettings {
-            sound_capacity: 8192,
+            // The sound effect mixer caps how many voices play at once, so sounds no longer pile up
+            sound_capacity: 512,
             command_capacity: 4096,
         });
 
@@ -79,6 +82,8 @@ impl Plugin for AppPlugin {
             ship::plugin,
             enemy::plugin,
             audio::plugin,
+            leaderboard::plugin,
+            achievement::plugin,
         ));
 
         // Enable dev tools for dev builds.
@@ -0,0 +1,73 @@
+//! An achievements screen that can be accessed from the title screen.
+use crate::achievement::{AchievementProgress, AchievementsResource};
+use crate::assets::audio_assets::Fonts;
+use crate::util::Colour;
+use crate::{screens::AppStates, theme::prelude::*};
+use bevy::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(AppStates::Achievements), spawn_achievements_screen);
+}
+
+fn spawn_achievements_screen(
+    mut commands: Commands,
+    fonts: Res<Fonts>,
+    localize: Res<Localize>,
+    achievements: Res<AchievementsResource>,
+    progress: Res<AchievementProgress>,
+) {
+    commands
+        .ui_root()
+        .insert(StateScoped(AppStates::Achievements))
+        .with_children(|children| {
+            children.header("achievement.title", fonts.primary.clone());
+            children.spawn((
+                Text::new(localize.format(
+                    "achievement.count",
+                    &[
+                        ("unlocked", &progress.unlocked.len()),
+                        ("total", &achievements.achievements.len()),
+                    ],
+                )),
+                TextFont {
+                    font: fonts.primary.clone(),
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Colour::WHITE),
+                LocalizeFont,
+            ));
+            for achievement in &achievements.achievements {
+                let unlocked = progress.is_unlocked(&achievement.id);
+                children.spawn((
+                    Text::new(localize.format(
+                        "achievement.row",
+                        &[
+                            ("name", &localize.get(&achievement.name_key())),
+                            ("description", &localize.get(&achievement.description_key())),
+                            ("progress", &progress.get(&achievement.id)),
+                            ("target", &achievement.condition.target()),
+                        ],
+                    )),
+                    TextFont {
+                        font: fonts.primary.clone(),
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(if unlocked {
+                        Colour::YELLOW
+                    } else {
+                        Colour::INACTIVE
+                    }),
+                    LocalizeFont,
+                ));
+            }
+            children
+                .button("Back", fonts.primary.clone())
+                .observe(enter_title_screen);
+        });
+}
+
+fn enter_title_screen(_trigger: Trigger<OnPress>, mut next_screen: ResMut<NextState<AppStates>>) {
+    next_screen.set(AppStates::MainMenu);
+}
@@ -1,63 +1,371 @@
 //! The screen state for the main gameplay.
 use crate::assets::audio_assets::Fonts;
-use crate::components::audio::{BGMusicType, ChangeBackgroundMusicEvent};
+use crate::assets::ui::UiAssets;
+use crate::config::GameConfig;
+use crate::gameplay::adaptive::AdaptiveDifficulty;
+use crate::gameplay::daily::{DailyChallenge, DailyScored};
+use crate::gameplay::hangar::{award_credits, RunCredits};
+use crate::gameplay::highscore::{record_run, HighScores, PendingHighScore};
 use crate::gameplay::level::spawn_level as spawn_level_command;
 use crate::gameplay::loot::Points;
+use crate::gameplay::mode::GameMode;
+use crate::gameplay::rng::RunRng;
+use crate::gameplay::statistics::RunStatistics;
+use crate::gameplay::victory::{award_victory_bonus, VICTORY_BONUS};
 use crate::gameplay::GameStates;
+use crate::screens::highscores::spawn_table;
 use crate::theme::interaction::OnPress;
+use crate::util::Colour;
 use crate::{screens::AppStates, theme::prelude::*};
-use bevy::input::common_conditions::input_just_pressed;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
 use bevy::prelude::*;
-use std::time::Duration;
+
+/// Longest name accepted for the high-score table
+const MAX_NAME_LENGTH: usize = 12;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(AppStates::Game), spawn_level);
-    app.add_systems(OnEnter(GameStates::GameOver), setup_game_over);
+    app.add_systems(
+        OnEnter(GameStates::GameOver),
+        (
+            setup_game_over.after(record_run).after(award_credits),
+            spawn_run_summary,
+        ),
+    );
+    app.add_systems(
+        OnEnter(GameStates::Victory),
+        (setup_victory.after(award_victory_bonus), spawn_run_summary),
+    );
     app.add_systems(
         Update,
-        return_to_title_screen
-            .run_if(in_state(AppStates::Game).and(input_just_pressed(KeyCode::Escape))),
+        name_entry_system
+            .run_if(in_state(GameStates::GameOver).and(resource_exists::<PendingHighScore>)),
     );
 }
 
-fn spawn_level(
-    mut commands: Commands,
-    mut change_bg_music_event_writer: EventWriter<ChangeBackgroundMusicEvent>,
-) {
-    change_bg_music_event_writer.send(ChangeBackgroundMusicEvent {
-        bg_music_type: Some(BGMusicType::Game),
-        loop_from: Some(0.0),
-        fade_in: Some(Duration::from_secs(2)),
-        fade_out: Some(Duration::from_secs(2)),
-    });
+fn spawn_level(mut commands: Commands) {
     commands.queue(spawn_level_command);
 }
 
-fn return_to_title_screen(
-    mut next_screen: ResMut<NextState<AppStates>>,
-    mut change_bg_music_event_writer: EventWriter<ChangeBackgroundMusicEvent>,
-) {
-    // fade music out
-    change_bg_music_event_writer.send(ChangeBackgroundMusicEvent {
-        fade_out: Some(Duration::from_secs(2)),
-        ..default()
-    });
-    next_screen.set(AppStates::MainMenu);
-}
+/// Text showing the name being typed for a new record
+#[derive(Component)]
+struct NameEntry;
+
+/// Widgets of the name prompt, removed once the name is confirmed
+#[derive(Component)]
+struct NameEntryPrompt;
+
+/// Node the high-score table is spawned into
+#[derive(Component)]
+struct HighScoreTable;
 
-fn setup_game_over(mut commands: Commands, points: Res<Points>, fonts: Res<Fonts>) {
+fn setup_game_over(
+    mut commands: Commands,
+    points: Res<Points>,
+    run_credits: Res<RunCredits>,
+    fonts: Res<Fonts>,
+    localize: Res<Localize>,
+    high_scores: Res<HighScores>,
+    mode: Res<GameMode>,
+    challenge: Res<DailyChallenge>,
+    scored: Res<DailyScored>,
+    rng: Res<RunRng>,
+    pending: Option<Res<PendingHighScore>>,
+) {
     commands
         .ui_root()
         .insert(StateScoped(GameStates::GameOver))
         .with_children(|children| {
-            children.content(format!("{}", points.into_inner()));
-            children.label("points", fonts.primary.clone());
+            children
+                .content(localize.plural("game_over.points", points.value as usize, &[]))
+                .insert(LocalizeFont);
+            children
+                .content(localize.format("hangar.earned", &[("credits", &run_credits.0)]))
+                .insert(LocalizeFont);
+            if *mode == GameMode::Daily {
+                children
+                    .content(localize.format(
+                        "daily.result",
+                        &[("date", &challenge.date), ("seed", &rng.seed)],
+                    ))
+                    .insert(LocalizeFont);
+                if !scored.0 {
+                    children.label("daily.practice_run", fonts.primary.clone());
+                }
+            }
+            if let Some(pending) = &pending {
+                children
+                    .label("high_score.new_record", fonts.primary.clone())
+                    .insert(NameEntryPrompt);
+                children.content(name_entry_text(&pending.0.name)).insert((
+                    NameEntry,
+                    NameEntryPrompt,
+                    LocalizeFont,
+                ));
+                children
+                    .label("high_score.enter_name", fonts.primary.clone())
+                    .insert(NameEntryPrompt);
+            }
+            children
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    HighScoreTable,
+                ))
+                .with_children(|children| {
+                    if pending.is_none() {
+                        spawn_table(children, &fonts, &localize, &high_scores, *mode, None);
+                    }
+                });
             children
                 .button("Return To Title", fonts.primary.clone())
                 .observe(return_title_screen);
         });
 }
 
+/// Panel beside the game over and victory menus listing what happened during the run
+fn spawn_run_summary(
+    mut commands: Commands,
+    fonts: Res<Fonts>,
+    localize: Res<Localize>,
+    statistics: Res<RunStatistics>,
+    adaptive: Res<AdaptiveDifficulty>,
+    game_state: Res<State<GameStates>>,
+) {
+    let mut lines = vec![(localize.get("stats.title").to_string(), Colour::YELLOW)];
+    lines.push((
+        localize.format("stats.kills", &[("count", &statistics.total_kills())]),
+        Colour::WHITE,
+    ));
+    for (mob_type, count) in statistics.kills_by_mob() {
+        lines.push((
+            localize.format(
+                "stats.kill_row",
+                &[("mob", &localize.get_key(&mob_type)), ("count", &count)],
+            ),
+            Colour::RED,
+        ));
+    }
+    lines.push((
+        localize.get("stats.damage_dealt").to_string(),
+        Colour::WHITE,
+    ));
+    for (class, damage) in statistics.damage_by_weapon() {
+        let shots = statistics.shots_fired.get(&class).copied().unwrap_or(0);
+        lines.push((
+            localize.format(
+                "stats.damage_row",
+                &[
+                    ("weapon", &localize.get_key(&class)),
+                    ("damage", &damage),
+                    ("shots", &shots),
+                ],
+            ),
+            Colour::PLAYER,
+        ));
+    }
+    lines.push((
+        localize.format(
+            "stats.damage_taken",
+            &[
+                ("damage", &statistics.damage_taken),
+                ("shields", &statistics.shields_absorbed),
+            ],
+        ),
+        Colour::WHITE,
+    ));
+    lines.push((
+        localize.format("stats.loot", &[("cargo", &statistics.loot_collected)]),
+        Colour::WHITE,
+    ));
+    lines.push((localize.get("stats.upgrades").to_string(), Colour::WHITE));
+    for (seconds, upgrade) in &statistics.upgrades {
+        lines.push((
+            localize.format(
+                "stats.upgrade_row",
+                &[
+                    (
+                        "time",
+                        &format!("{:0>2}:{:0>2}", seconds / 60, seconds % 60),
+                    ),
+                    ("upgrade", &localize.get_key(upgrade)),
+                ],
+            ),
+            Colour::GREEN,
+        ));
+    }
+    if adaptive.enabled {
+        lines.push((
+            localize.format(
+                "stats.adaptive",
+                &[
+                    ("performance", &format!("{:+.2}", adaptive.performance)),
+                    ("budget", &format!("{:.0}", adaptive.spawn_budget() * 100.0)),
+                    (
+                        "accuracy",
+                        &format!("{:.0}", adaptive.enemy_accuracy() * 100.0),
+                    ),
+                    ("drops", &format!("{:.0}", adaptive.drop_rate() * 100.0)),
+                ],
+            ),
+            Colour::YELLOW,
+        ));
+    }
+
+    commands
+        .spawn((
+            Name::new("Run Summary"),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(20.0),
+                top: Val::Px(20.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            StateScoped(*game_state.get()),
+        ))
+        .with_children(|children| {
+            for (line, colour) in lines {
+                children.spawn((
+                    Text::new(line),
+                    TextFont {
+                        font: fonts.primary.clone(),
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(colour),
+                    LocalizeFont,
+                ));
+            }
+        });
+}
+
+fn setup_victory(
+    mut commands: Commands,
+    points: Res<Points>,
+    fonts: Res<Fonts>,
+    ui_assets: Res<UiAssets>,
+    localize: Res<Localize>,
+    mode: Res<GameMode>,
+) {
+    // 背景与菜单同属一个根节点，离开胜利状态时一并清除
+    commands
+        .ui_root()
+        .insert(StateScoped(GameStates::Victory))
+        .with_children(|children| {
+            children.spawn((
+                Name::new("Victory Background"),
+                ImageNode {
+                    image: ui_assets.victory_background.clone(),
+                    color: Color::srgba(1.0, 1.0, 1.0, 0.8),
+                    ..default()
+                },
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+            ));
+            children.header("victory.title", fonts.primary.clone());
+            children
+                .content(localize.format("victory.bonus", &[("bonus", &VICTORY_BONUS)]))
+                .insert(LocalizeFont);
+            children
+                .content(localize.plural("game_over.points", points.value as usize, &[]))
+                .insert(LocalizeFont);
+            // 每日挑战要保持同样的规则，只有经典模式可以转入无尽模式
+            if *mode == GameMode::Classic {
+                children
+                    .button("victory.endless", fonts.primary.clone())
+                    .observe(continue_endless);
+            }
+            children
+                .button("victory.finish", fonts.primary.clone())
+                .observe(finish_run);
+        });
+}
+
+/// Keeps the run going as an endless run, recorded in the endless score table
+fn continue_endless(
+    _trigger: Trigger<OnPress>,
+    mut mode: ResMut<GameMode>,
+    mut next_state: ResMut<NextState<GameStates>>,
+) {
+    *mode = GameMode::Endless;
+    next_state.set(GameStates::Playing);
+}
+
+/// Ends the run through the game over screen, where it is recorded
+fn finish_run(_trigger: Trigger<OnPress>, mut next_state: ResMut<NextState<GameStates>>) {
+    next_state.set(GameStates::GameOver);
+}
+
+fn name_entry_text(name: &str) -> String {
+    format!("{}_", name)
+}
+
+/// Types the player's name for a new record, Enter saves it and shows the table
+fn name_entry_system(
+    mut commands: Commands,
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut pending: ResMut<PendingHighScore>,
+    mut high_scores: ResMut<HighScores>,
+    mut config: ResMut<GameConfig>,
+    mode: Res<GameMode>,
+    fonts: Res<Fonts>,
+    localize: Res<Localize>,
+    mut name_entry: Query<&mut Text, With<NameEntry>>,
+    prompt: Query<Entity, With<NameEntryPrompt>>,
+    table: Query<Entity, With<HighScoreTable>>,
+) {
+    for ev in keyboard_events.read() {
+        if ev.state != ButtonState::Pressed {
+            continue;
+        }
+        let name = &mut pending.0.name;
+        match &ev.logical_key {
+            Key::Enter => {
+                let rank = pending.commit(&mut high_scores, &mut config);
+                commands.remove_resource::<PendingHighScore>();
+                for entity in &prompt {
+                    commands.entity(entity).despawn_recursive();
+                }
+                if let Ok(table) = table.get_single() {
+                    commands.entity(table).with_children(|children| {
+                        spawn_table(children, &fonts, &localize, &high_scores, *mode, rank);
+                    });
+                }
+                return;
+            }
+            Key::Backspace => {
+                name.pop();
+            }
+            Key::Character(input) => {
+                for character in input.chars().filter(|c| !c.is_control()) {
+                    if name.chars().count() < MAX_NAME_LENGTH {
+                        name.push(character);
+                    }
+                }
+            }
+            Key::Space => {
+                if name.chars().count() < MAX_NAME_LENGTH {
+                    name.push(' ');
+                }
+            }
+            _ => continue,
+        }
+        if let Ok(mut text) = name_entry.get_single_mut() {
+            text.0 = name_entry_text(name);
+        }
+    }
+}
+
 fn return_title_screen(_trigger: Trigger<OnPress>, mut next_screen: ResMut<NextState<AppStates>>) {
     next_screen.set(AppStates::MainMenu);
 }
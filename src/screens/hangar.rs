@@ -0,0 +1,169 @@
+//! The hangar, where credits earned in runs are spent on permanent upgrades.
+use crate::assets::audio_assets::Fonts;
+use crate::gameplay::hangar::{Hangar, HangarUpgrade};
+use crate::util::Colour;
+use crate::{screens::AppStates, theme::prelude::*};
+use bevy::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        OnEnter(AppStates::Hangar),
+        (spawn_hangar_screen, update_hangar_items).chain(),
+    );
+    app.add_systems(
+        Update,
+        update_hangar_items.run_if(in_state(AppStates::Hangar).and(resource_changed::<Hangar>)),
+    );
+}
+
+/// Node the credits and upgrades are spawned into
+#[derive(Component)]
+struct HangarItems;
+
+/// Buy button of an upgrade
+#[derive(Component)]
+struct HangarItem(HangarUpgrade);
+
+fn spawn_hangar_screen(mut commands: Commands, fonts: Res<Fonts>) {
+    commands
+        .ui_root()
+        .insert(StateScoped(AppStates::Hangar))
+        .with_children(|children| {
+            children.header("hangar.title", fonts.primary.clone());
+            children.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(10.0),
+                    ..default()
+                },
+                HangarItems,
+            ));
+            children
+                .button("Back", fonts.primary.clone())
+                .observe(enter_title_screen);
+        });
+}
+
+fn update_hangar_items(
+    mut commands: Commands,
+    fonts: Res<Fonts>,
+    localize: Res<Localize>,
+    hangar: Res<Hangar>,
+    node: Query<Entity, With<HangarItems>>,
+) {
+    let Ok(node) = node.get_single() else {
+        return;
+    };
+    commands
+        .entity(node)
+        .despawn_descendants()
+        .with_children(|children| {
+            children.spawn((
+                Text::new(localize.format("hangar.credits", &[("credits", &hangar.credits)])),
+                TextFont {
+                    font: fonts.primary.clone(),
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Colour::YELLOW),
+                LocalizeFont,
+            ));
+            for upgrade in HangarUpgrade::ALL {
+                let next_cost = hangar.next_cost(upgrade);
+                children
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(20.0),
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        row.spawn((
+                            Text::new(localize.format(
+                                "hangar.row",
+                                &[
+                                    ("name", &localize.get_key(&upgrade)),
+                                    ("description", &localize.get(&upgrade.describe())),
+                                    ("level", &hangar.level(upgrade)),
+                                    ("max", &upgrade.max_level()),
+                                ],
+                            )),
+                            TextFont {
+                                font: fonts.primary.clone(),
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(Colour::WHITE),
+                            LocalizeFont,
+                            Node {
+                                width: Val::Px(500.0),
+                                ..default()
+                            },
+                        ));
+                        match next_cost {
+                            Some(cost) => {
+                                row.spawn((
+                                    Button,
+                                    Node {
+                                        width: Val::Px(160.0),
+                                        height: Val::Px(40.0),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..default()
+                                    },
+                                    BackgroundColor(ui_palette::NODE_BACKGROUND),
+                                    InteractionPalette {
+                                        none: ui_palette::NODE_BACKGROUND,
+                                        hovered: ui_palette::BUTTON_HOVERED_BACKGROUND,
+                                        pressed: ui_palette::BUTTON_PRESSED_BACKGROUND,
+                                    },
+                                    HangarItem(upgrade),
+                                ))
+                                .with_child((
+                                    Text::new(localize.format("hangar.buy", &[("cost", &cost)])),
+                                    TextFont {
+                                        font: fonts.primary.clone(),
+                                        font_size: 14.0,
+                                        ..default()
+                                    },
+                                    TextColor(if cost <= hangar.credits {
+                                        ui_palette::BUTTON_TEXT
+                                    } else {
+                                        Colour::INACTIVE
+                                    }),
+                                    LocalizeFont,
+                                ))
+                                .observe(buy_upgrade);
+                            }
+                            None => {
+                                row.spawn((
+                                    Text::new(localize.get("hangar.maxed")),
+                                    TextFont {
+                                        font: fonts.primary.clone(),
+                                        font_size: 14.0,
+                                        ..default()
+                                    },
+                                    TextColor(Colour::INACTIVE),
+                                    LocalizeFont,
+                                    Node {
+                                        width: Val::Px(160.0),
+                                        ..default()
+                                    },
+                                ));
+                            }
+                        }
+                    });
+            }
+        });
+}
+
+fn buy_upgrade(trigger: Trigger<OnPress>, items: Query<&HangarItem>, mut hangar: ResMut<Hangar>) {
+    if let Ok(item) = items.get(trigger.entity()) {
+        hangar.buy(item.0);
+    }
+}
+
+fn enter_title_screen(_trigger: Trigger<OnPress>, mut next_screen: ResMut<NextState<AppStates>>) {
+    next_screen.set(AppStates::MainMenu);
+}
@@ -0,0 +1,267 @@
+//! A high-score screen that can be accessed from the title screen.
+use crate::assets::audio_assets::Fonts;
+use crate::config::GameConfig;
+use crate::gameplay::highscore::HighScores;
+use crate::gameplay::mode::GameMode;
+use crate::leaderboard::{Leaderboard, Rankings};
+use crate::util::Colour;
+use crate::{screens::AppStates, theme::prelude::*};
+use bevy::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<ShownMode>();
+    app.add_systems(
+        OnEnter(AppStates::HighScores),
+        (
+            spawn_high_scores_screen,
+            update_local_table,
+            refresh_rankings,
+        )
+            .chain(),
+    );
+    app.add_systems(
+        Update,
+        (update_local_table, refresh_rankings)
+            .run_if(in_state(AppStates::HighScores).and(resource_changed::<ShownMode>)),
+    );
+    app.add_systems(
+        Update,
+        update_global_rankings.run_if(
+            in_state(AppStates::HighScores)
+                .and(resource_changed::<Leaderboard>.or(resource_changed::<GameConfig>)),
+        ),
+    );
+}
+
+/// Game mode whose local table and global rankings are shown
+#[derive(Resource, Default)]
+struct ShownMode(GameMode);
+
+/// Button showing the table of a game mode
+#[derive(Component)]
+struct ModeTab(GameMode);
+
+/// Node the local table is spawned into
+#[derive(Component)]
+struct LocalTable;
+
+/// Node the global rankings are spawned into
+#[derive(Component)]
+struct GlobalRankings;
+
+fn spawn_high_scores_screen(mut commands: Commands, fonts: Res<Fonts>) {
+    commands
+        .ui_root()
+        .insert(StateScoped(AppStates::HighScores))
+        .with_children(|children| {
+            children.header("high_score.title", fonts.primary.clone());
+            children
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(10.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    for mode in GameMode::ALL {
+                        row.button(mode.localize_key(), fonts.primary.clone())
+                            .insert(ModeTab(mode))
+                            .observe(show_mode);
+                    }
+                });
+            children.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                LocalTable,
+            ));
+            children.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                GlobalRankings,
+            ));
+            children
+                .button("leaderboard.toggle", fonts.primary.clone())
+                .observe(toggle_online);
+            children
+                .button("Back", fonts.primary.clone())
+                .observe(enter_title_screen);
+        });
+}
+
+fn update_local_table(
+    mut commands: Commands,
+    fonts: Res<Fonts>,
+    localize: Res<Localize>,
+    high_scores: Res<HighScores>,
+    shown: Res<ShownMode>,
+    node: Query<Entity, With<LocalTable>>,
+) {
+    let Ok(node) = node.get_single() else {
+        return;
+    };
+    commands
+        .entity(node)
+        .despawn_descendants()
+        .with_children(|children| {
+            children.label(shown.0.localize_key(), fonts.primary.clone());
+            spawn_table(children, &fonts, &localize, &high_scores, shown.0, None);
+        });
+}
+
+fn show_mode(trigger: Trigger<OnPress>, tabs: Query<&ModeTab>, mut shown: ResMut<ShownMode>) {
+    if let Ok(tab) = tabs.get(trigger.entity()) {
+        shown.0 = tab.0;
+    }
+}
+
+/// One line per run of a game mode, `highlight` marks the rank of a record that was just set
+pub(super) fn spawn_table(
+    children: &mut ChildBuilder,
+    fonts: &Fonts,
+    localize: &Localize,
+    high_scores: &HighScores,
+    mode: GameMode,
+    highlight: Option<usize>,
+) {
+    let table = high_scores.table(mode);
+    if table.is_empty() {
+        children.label("high_score.empty", fonts.primary.clone());
+        return;
+    }
+    for (rank, high_score) in table.into_iter().enumerate() {
+        let weapons = high_score
+            .weapons
+            .iter()
+            .map(|weapon| localize.get(weapon))
+            .collect::<Vec<_>>()
+            .join(", ");
+        children.spawn((
+            Text::new(localize.format(
+                "high_score.row",
+                &[
+                    ("rank", &(rank + 1)),
+                    ("name", &high_score.name),
+                    ("score", &high_score.score),
+                    (
+                        "time",
+                        &format!(
+                            "{:0>2}:{:0>2}",
+                            high_score.seconds / 60,
+                            high_score.seconds % 60
+                        ),
+                    ),
+                    ("level", &high_score.level),
+                    ("weapons", &weapons),
+                    ("difficulty", &localize.get_key(&high_score.difficulty)),
+                ],
+            )),
+            TextFont {
+                font: fonts.primary.clone(),
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(if highlight == Some(rank) {
+                Colour::YELLOW
+            } else {
+                Colour::WHITE
+            }),
+            LocalizeFont,
+        ));
+    }
+}
+
+/// Shows the global rankings, or why only local scores are available
+fn update_global_rankings(
+    mut commands: Commands,
+    fonts: Res<Fonts>,
+    localize: Res<Localize>,
+    config: Res<GameConfig>,
+    leaderboard: Res<Leaderboard>,
+    node: Query<Entity, With<GlobalRankings>>,
+) {
+    let Ok(node) = node.get_single() else {
+        return;
+    };
+    commands
+        .entity(node)
+        .despawn_descendants()
+        .with_children(|children| {
+            if !config.online {
+                children.label("leaderboard.disabled", fonts.primary.clone());
+                return;
+            }
+            match &leaderboard.rankings {
+                Rankings::Idle | Rankings::Loading => {
+                    children.label("leaderboard.loading", fonts.primary.clone());
+                }
+                Rankings::Unavailable => {
+                    children.label("leaderboard.unavailable", fonts.primary.clone());
+                }
+                Rankings::Global(entries) => {
+                    children.label("leaderboard.global", fonts.primary.clone());
+                    if entries.is_empty() {
+                        children.label("high_score.empty", fonts.primary.clone());
+                    }
+                    for entry in entries {
+                        children.spawn((
+                            Text::new(localize.format(
+                                "leaderboard.row",
+                                &[
+                                    ("rank", &entry.rank),
+                                    ("name", &entry.name),
+                                    ("score", &entry.score),
+                                    (
+                                        "time",
+                                        &format!(
+                                            "{:0>2}:{:0>2}",
+                                            entry.seconds / 60,
+                                            entry.seconds % 60
+                                        ),
+                                    ),
+                                ],
+                            )),
+                            TextFont {
+                                font: fonts.primary.clone(),
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(Colour::PLAYER),
+                            LocalizeFont,
+                        ));
+                    }
+                }
+            }
+        });
+}
+
+/// Fetches the global rankings of the shown mode
+fn refresh_rankings(
+    config: Res<GameConfig>,
+    shown: Res<ShownMode>,
+    mut leaderboard: ResMut<Leaderboard>,
+) {
+    if config.online {
+        leaderboard.refresh(shown.0);
+    }
+}
+
+fn toggle_online(
+    _trigger: Trigger<OnPress>,
+    shown: Res<ShownMode>,
+    mut config: ResMut<GameConfig>,
+    mut leaderboard: ResMut<Leaderboard>,
+) {
+    config.online = !config.online;
+    if config.online {
+        leaderboard.refresh(shown.0);
+    }
+}
+
+fn enter_title_screen(_trigger: Trigger<OnPress>, mut next_screen: ResMut<NextState<AppStates>>) {
+    next_screen.set(AppStates::MainMenu);
+}
@@ -1,8 +1,13 @@
 //! The game's main screen states and transitions between them.
 
+mod achievements;
 mod credits;
 mod gameplay;
+mod hangar;
+mod highscores;
 mod loading;
+mod new_run;
+mod pause;
 mod splash;
 mod title;
 
@@ -13,9 +18,14 @@ pub(super) fn plugin(app: &mut App) {
     app.enable_state_scoped_entities::<AppStates>();
 
     app.add_plugins((
+        achievements::plugin,
         credits::plugin,
         gameplay::plugin,
+        hangar::plugin,
+        highscores::plugin,
         loading::plugin,
+        new_run::plugin,
+        pause::plugin,
         splash::plugin,
         title::plugin,
     ));
@@ -29,5 +39,12 @@ pub enum AppStates {
     Loading,
     MainMenu,
     Credits,
+    HighScores,
+    Achievements,
+    Hangar,
+    /// Picking the difficulty before a run
+    NewRun,
     Game,
+    /// Passed through to start a new run from within a run
+    Restart,
 }
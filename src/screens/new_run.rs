@@ -0,0 +1,171 @@
+//! The screen between the title and a run, where the difficulty of the chosen mode is picked.
+use crate::assets::audio_assets::Fonts;
+use crate::config::GameConfig;
+use crate::gameplay::daily::{refresh_daily_challenge, DailyAttempt, DailyChallenge};
+use crate::gameplay::difficulty::Difficulty;
+use crate::gameplay::mode::GameMode;
+use crate::{screens::AppStates, theme::prelude::*};
+use bevy::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        OnEnter(AppStates::NewRun),
+        (
+            spawn_new_run_screen.after(refresh_daily_challenge),
+            update_adaptive_toggle,
+        )
+            .chain(),
+    );
+    app.add_systems(
+        Update,
+        update_adaptive_toggle
+            .run_if(in_state(AppStates::NewRun).and(resource_changed::<GameConfig>)),
+    );
+}
+
+/// Button starting a run on this difficulty
+#[derive(Component)]
+struct DifficultyButton(Difficulty);
+
+/// Node the adaptive difficulty toggle is spawned into
+#[derive(Component)]
+struct AdaptiveToggle;
+
+fn spawn_new_run_screen(
+    mut commands: Commands,
+    fonts: Res<Fonts>,
+    localize: Res<Localize>,
+    mode: Res<GameMode>,
+    challenge: Res<DailyChallenge>,
+    attempt: Res<DailyAttempt>,
+) {
+    commands
+        .ui_root()
+        .insert(StateScoped(AppStates::NewRun))
+        .with_children(|children| {
+            children.header(mode.localize_key(), fonts.primary.clone());
+            if *mode == GameMode::Daily {
+                spawn_daily_challenge(children, &fonts, &localize, &challenge, &attempt);
+                children
+                    .button("Back", fonts.primary.clone())
+                    .observe(enter_title_screen);
+                return;
+            }
+            children.label("difficulty.title", fonts.primary.clone());
+            for difficulty in Difficulty::ALL {
+                children
+                    .button(difficulty.localize_key(), fonts.primary.clone())
+                    .insert(DifficultyButton(difficulty))
+                    .observe(start_run);
+            }
+            children.spawn((Node::default(), AdaptiveToggle));
+            children
+                .button("Back", fonts.primary.clone())
+                .observe(enter_title_screen);
+        });
+}
+
+/// Today's seed, difficulty and modifiers, and whether the scored attempt is spent
+fn spawn_daily_challenge(
+    children: &mut ChildBuilder,
+    fonts: &Fonts,
+    localize: &Localize,
+    challenge: &DailyChallenge,
+    attempt: &DailyAttempt,
+) {
+    children
+        .content(localize.format(
+            "daily.info",
+            &[
+                ("date", &challenge.date),
+                ("seed", &challenge.seed),
+                ("difficulty", &localize.get_key(&challenge.difficulty)),
+            ],
+        ))
+        .insert(LocalizeFont);
+    for modifier in &challenge.modifiers {
+        children
+            .content(localize.format(
+                "daily.modifier",
+                &[
+                    ("name", &localize.get_key(modifier)),
+                    ("description", &localize.get(&modifier.describe())),
+                ],
+            ))
+            .insert(LocalizeFont);
+    }
+    let attempted = attempt.attempted(challenge);
+    if attempted {
+        let status = match attempt.score {
+            Some(score) => localize.format("daily.played", &[("score", &score)]),
+            None => localize.get("daily.abandoned").to_string(),
+        };
+        children.content(status).insert(LocalizeFont);
+    }
+    children
+        .button(
+            if attempted {
+                "daily.practice"
+            } else {
+                "daily.start"
+            },
+            fonts.primary.clone(),
+        )
+        .observe(start_daily);
+}
+
+fn update_adaptive_toggle(
+    mut commands: Commands,
+    fonts: Res<Fonts>,
+    config: Res<GameConfig>,
+    node: Query<Entity, With<AdaptiveToggle>>,
+) {
+    let Ok(node) = node.get_single() else {
+        return;
+    };
+    commands
+        .entity(node)
+        .despawn_descendants()
+        .with_children(|children| {
+            children
+                .button(
+                    if config.adaptive_difficulty {
+                        "adaptive.on"
+                    } else {
+                        "adaptive.off"
+                    },
+                    fonts.primary.clone(),
+                )
+                .observe(toggle_adaptive);
+        });
+}
+
+fn toggle_adaptive(_trigger: Trigger<OnPress>, mut config: ResMut<GameConfig>) {
+    config.adaptive_difficulty = !config.adaptive_difficulty;
+}
+
+fn start_run(
+    trigger: Trigger<OnPress>,
+    buttons: Query<&DifficultyButton>,
+    mut difficulty: ResMut<Difficulty>,
+    mut next_screen: ResMut<NextState<AppStates>>,
+) {
+    if let Ok(button) = buttons.get(trigger.entity()) {
+        *difficulty = button.0;
+        next_screen.set(AppStates::Game);
+    }
+}
+
+fn start_daily(
+    _trigger: Trigger<OnPress>,
+    challenge: Res<DailyChallenge>,
+    mut difficulty: ResMut<Difficulty>,
+    mut next_screen: ResMut<NextState<AppStates>>,
+) {
+    *difficulty = challenge.difficulty;
+    next_screen.set(AppStates::Game);
+}
+
+fn enter_title_screen(_trigger: Trigger<OnPress>, mut next_screen: ResMut<NextState<AppStates>>) {
+    next_screen.set(AppStates::MainMenu);
+}
@@ -0,0 +1,210 @@
+//! The pause menu drawn over the game while it is paused.
+use crate::assets::audio_assets::Fonts;
+use crate::config::GameConfig;
+use crate::gameplay::GameStates;
+use crate::{screens::AppStates, theme::prelude::*};
+use bevy::ecs::system::IntoObserverSystem;
+use bevy::prelude::*;
+use bevy::window::WindowMode;
+
+/// Volume change of one press on the settings page
+const VOLUME_STEP: f32 = 0.1;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<PausePage>()
+        .add_systems(
+            OnEnter(GameStates::Paused),
+            (spawn_pause_menu, update_pause_menu).chain(),
+        )
+        .add_systems(
+            Update,
+            update_pause_menu.run_if(
+                in_state(GameStates::Paused)
+                    .and(resource_changed::<PausePage>.or(resource_changed::<GameConfig>)),
+            ),
+        )
+        .add_systems(OnEnter(AppStates::Restart), restart_run);
+}
+
+/// Page of the pause menu being shown
+#[derive(Resource, Default, PartialEq)]
+enum PausePage {
+    #[default]
+    Main,
+    Settings,
+}
+
+/// Node the buttons of the current page are spawned into
+#[derive(Component)]
+struct PauseMenu;
+
+fn spawn_pause_menu(mut commands: Commands, mut page: ResMut<PausePage>) {
+    *page = PausePage::Main;
+    commands
+        .ui_root()
+        .insert((
+            StateScoped(GameStates::Paused),
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            GlobalZIndex(5),
+        ))
+        .with_children(|children| {
+            children.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(10.0),
+                    ..default()
+                },
+                PauseMenu,
+            ));
+        });
+}
+
+fn update_pause_menu(
+    mut commands: Commands,
+    fonts: Res<Fonts>,
+    localize: Res<Localize>,
+    config: Res<GameConfig>,
+    page: Res<PausePage>,
+    menu: Query<Entity, With<PauseMenu>>,
+) {
+    let Ok(menu) = menu.get_single() else {
+        return;
+    };
+    commands
+        .entity(menu)
+        .despawn_descendants()
+        .with_children(|children| match *page {
+            PausePage::Main => {
+                children.header("pause.title", fonts.primary.clone());
+                children
+                    .button("pause.resume", fonts.primary.clone())
+                    .observe(resume);
+                children
+                    .button("pause.restart", fonts.primary.clone())
+                    .observe(restart);
+                children
+                    .button("pause.settings", fonts.primary.clone())
+                    .observe(open_settings);
+                children
+                    .button("pause.quit", fonts.primary.clone())
+                    .observe(quit_to_title);
+            }
+            PausePage::Settings => {
+                children.header("pause.settings", fonts.primary.clone());
+                children
+                    .content(localize.format(
+                        "settings.music_volume",
+                        &[("volume", &volume_percent(config.bgm_volume))],
+                    ))
+                    .insert(LocalizeFont);
+                volume_buttons(children, &fonts, lower_music, raise_music);
+                children
+                    .content(localize.format(
+                        "settings.sound_volume",
+                        &[("volume", &volume_percent(config.sfx_volume))],
+                    ))
+                    .insert(LocalizeFont);
+                volume_buttons(children, &fonts, lower_sound, raise_sound);
+                children
+                    .button("Duolingo", fonts.primary.clone())
+                    .insert(FixedFont)
+                    .observe(toggle_language);
+                children
+                    .button("Toggle Fullscreen", fonts.primary.clone())
+                    .observe(toggle_fullscreen);
+                children
+                    .button("Back", fonts.primary.clone())
+                    .observe(close_settings);
+            }
+        });
+}
+
+fn volume_percent(volume: f32) -> u32 {
+    (volume * 100.0).round() as u32
+}
+
+fn volume_buttons<M1, M2>(
+    children: &mut ChildBuilder,
+    fonts: &Fonts,
+    lower: impl IntoObserverSystem<OnPress, (), M1>,
+    raise: impl IntoObserverSystem<OnPress, (), M2>,
+) {
+    children
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(10.0),
+            ..default()
+        })
+        .with_children(|row| {
+            row.button("settings.lower", fonts.primary.clone())
+                .observe(lower);
+            row.button("settings.raise", fonts.primary.clone())
+                .observe(raise);
+        });
+}
+
+fn step_volume(volume: f32, step: f32) -> f32 {
+    ((volume + step) / VOLUME_STEP).round() * VOLUME_STEP
+}
+
+fn lower_music(_trigger: Trigger<OnPress>, mut config: ResMut<GameConfig>) {
+    config.bgm_volume = step_volume(config.bgm_volume, -VOLUME_STEP).max(0.0);
+}
+
+fn raise_music(_trigger: Trigger<OnPress>, mut config: ResMut<GameConfig>) {
+    config.bgm_volume = step_volume(config.bgm_volume, VOLUME_STEP).min(1.0);
+}
+
+fn lower_sound(_trigger: Trigger<OnPress>, mut config: ResMut<GameConfig>) {
+    config.sfx_volume = step_volume(config.sfx_volume, -VOLUME_STEP).max(0.0);
+}
+
+fn raise_sound(_trigger: Trigger<OnPress>, mut config: ResMut<GameConfig>) {
+    config.sfx_volume = step_volume(config.sfx_volume, VOLUME_STEP).min(1.0);
+}
+
+fn toggle_language(
+    _trigger: Trigger<OnPress>,
+    mut config: ResMut<GameConfig>,
+    mut localize: ResMut<Localize>,
+) {
+    match config.language.as_str() {
+        "English" => config.set_lang("Chinese"),
+        "Chinese" => config.set_lang("English"),
+        &_ => {}
+    }
+    localize.set_language(config.language.clone());
+}
+
+fn toggle_fullscreen(_trigger: Trigger<OnPress>, mut window: Single<&mut Window>) {
+    window.mode = match window.mode {
+        WindowMode::Windowed => WindowMode::SizedFullscreen(MonitorSelection::Current),
+        _ => WindowMode::Windowed,
+    };
+}
+
+fn resume(_trigger: Trigger<OnPress>, mut next_state: ResMut<NextState<GameStates>>) {
+    next_state.set(GameStates::Playing);
+}
+
+fn open_settings(_trigger: Trigger<OnPress>, mut page: ResMut<PausePage>) {
+    *page = PausePage::Settings;
+}
+
+fn close_settings(_trigger: Trigger<OnPress>, mut page: ResMut<PausePage>) {
+    *page = PausePage::Main;
+}
+
+fn restart(_trigger: Trigger<OnPress>, mut next_screen: ResMut<NextState<AppStates>>) {
+    next_screen.set(AppStates::Restart);
+}
+
+/// Leaving the game and coming straight back starts a new run
+fn restart_run(mut next_screen: ResMut<NextState<AppStates>>) {
+    next_screen.set(AppStates::Game);
+}
+
+fn quit_to_title(_trigger: Trigger<OnPress>, mut next_screen: ResMut<NextState<AppStates>>) {
+    next_screen.set(AppStates::MainMenu);
+}
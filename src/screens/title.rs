@@ -1,12 +1,11 @@
 //! The title screen that appears when the game starts.
 
 use crate::assets::audio_assets::Fonts;
-use crate::components::audio::{BGMusicType, ChangeBackgroundMusicEvent};
 use crate::config::GameConfig;
+use crate::gameplay::mode::GameMode;
 use crate::{screens::AppStates, theme::prelude::*};
 use bevy::prelude::*;
 use bevy::window::WindowMode;
-use std::time::Duration;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(AppStates::MainMenu), setup_title_screen);
@@ -17,21 +16,33 @@ fn setup_title_screen(
     mut localize: ResMut<Localize>,
     config: Res<GameConfig>,
     fonts: Res<Fonts>,
-    mut change_bg_music_event_writer: EventWriter<ChangeBackgroundMusicEvent>,
 ) {
     localize.set_language(config.language.clone());
     commands
         .ui_root()
         .insert(StateScoped(AppStates::MainMenu))
         .with_children(|children| {
+            for mode in GameMode::ALL {
+                children
+                    .button(mode.localize_key(), fonts.primary.clone())
+                    .insert(ModeButton(mode))
+                    .observe(enter_new_run_screen);
+            }
             children
-                .button("Play", fonts.primary.clone())
-                .observe(enter_gameplay_screen);
+                .button("hangar.title", fonts.primary.clone())
+                .observe(enter_hangar_screen);
+            children
+                .button("high_score.title", fonts.primary.clone())
+                .observe(enter_high_scores_screen);
+            children
+                .button("achievement.title", fonts.primary.clone())
+                .observe(enter_achievements_screen);
             children
                 .button("Credits", fonts.primary.clone())
                 .observe(enter_credits_screen);
             children
                 .button("Duolingo", fonts.primary.clone())
+                .insert(FixedFont)
                 .observe(set_lang);
             children
                 .button("Toggle Fullscreen", fonts.primary.clone())
@@ -41,26 +52,46 @@ fn setup_title_screen(
                 .button("Exit", fonts.primary.clone())
                 .observe(exit_app);
         });
-    // change music
-    change_bg_music_event_writer.send(ChangeBackgroundMusicEvent {
-        bg_music_type: Some(BGMusicType::Main),
-        loop_from: Some(0.0),
-        fade_in: Some(Duration::from_secs(2)),
-        fade_out: Some(Duration::from_secs(2)),
-    });
 }
 
-fn enter_gameplay_screen(
-    _trigger: Trigger<OnPress>,
+/// Button starting a run in this mode
+#[derive(Component)]
+struct ModeButton(GameMode);
+
+fn enter_new_run_screen(
+    trigger: Trigger<OnPress>,
+    buttons: Query<&ModeButton>,
+    mut mode: ResMut<GameMode>,
     mut next_screen: ResMut<NextState<AppStates>>,
 ) {
-    next_screen.set(AppStates::Game);
+    if let Ok(button) = buttons.get(trigger.entity()) {
+        *mode = button.0;
+        next_screen.set(AppStates::NewRun);
+    }
+}
+
+fn enter_hangar_screen(_trigger: Trigger<OnPress>, mut next_screen: ResMut<NextState<AppStates>>) {
+    next_screen.set(AppStates::Hangar);
 }
 
 fn enter_credits_screen(_trigger: Trigger<OnPress>, mut next_screen: ResMut<NextState<AppStates>>) {
     next_screen.set(AppStates::Credits);
 }
 
+fn enter_high_scores_screen(
+    _trigger: Trigger<OnPress>,
+    mut next_screen: ResMut<NextState<AppStates>>,
+) {
+    next_screen.set(AppStates::HighScores);
+}
+
+fn enter_achievements_screen(
+    _trigger: Trigger<OnPress>,
+    mut next_screen: ResMut<NextState<AppStates>>,
+) {
+    next_screen.set(AppStates::Achievements);
+}
+
 fn set_lang(
     _trigger: Trigger<OnPress>,
     mut config: ResMut<GameConfig>,
@@ -1,14 +1,16 @@
-use crate::assets::audio_assets::AudioAssets;
+use crate::assets::effect::EffectAssets;
+use crate::components::audio::{PlaySoundEffectEvent, SoundEffectType};
 use crate::components::health::*;
-use crate::config::GameConfig;
-use crate::gameplay::gamelogic::{game_not_paused, Damage, TakeDamageEvent};
+use crate::components::spawnable::{EffectType, Faction, ProjectileType};
+use crate::gameplay::effects::spawn_effect;
+use crate::gameplay::gamelogic::{game_not_paused, Damage, DespawnWithScene, TakeDamageEvent};
 use crate::gameplay::physics::Collider;
 use crate::screens::AppStates;
+use crate::ship::turret::TurretClass;
+use crate::util::{Math, RenderLayer};
 use crate::AppSet;
-use bevy::{prelude::*, utils::HashMap};
-use bevy_kira_audio::prelude::Volume;
-use bevy_kira_audio::{Audio, AudioControl};
-use bevy_prototype_lyon::prelude::{GeometryBuilder, Path, Stroke};
+use bevy::{ecs::system::EntityCommands, prelude::*, sprite::Anchor, utils::HashMap};
+use bevy_prototype_lyon::prelude::{GeometryBuilder, Path, ShapeBundle, Stroke};
 use bevy_prototype_lyon::shapes;
 
 #[derive(Component)]
@@ -53,9 +55,52 @@ impl Default for Bullet {
 #[derive(Component)]
 pub struct LaserRender;
 
+/// Projectile sprites are authored a few pixels wide, scale them up to match the ships
+pub const PROJECTILE_SPRITE_SCALE: f32 = 2.0;
+
 #[derive(Component)]
 pub struct DirectDamage(pub Damage);
 
+/// Turret that fired a projectile and the ship carrying it, carried into the `TakeDamageEvent`s
+/// it causes
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct DamageSource {
+    pub class: TurretClass,
+    pub ship: Entity,
+}
+
+/// Spritesheets played where a projectile hits something or runs out of time
+#[derive(Component, Clone)]
+pub struct ProjectileEffects {
+    pub impact: EffectType,
+    pub despawn: EffectType,
+}
+
+impl ProjectileEffects {
+    /// Effects matching the art of a projectile, there are none for beams and neutral projectiles
+    pub fn from_projectile(projectile_type: &ProjectileType) -> Option<Self> {
+        let (impact, despawn) = match projectile_type {
+            ProjectileType::Blast(Faction::Ally) => {
+                (EffectType::AllyBlastExplosion, EffectType::AllyBlastDespawn)
+            }
+            ProjectileType::Blast(Faction::Enemy) => (
+                EffectType::EnemyBlastExplosion,
+                EffectType::EnemyBlastDespawn,
+            ),
+            ProjectileType::Bullet(Faction::Ally) => (
+                EffectType::AllyBulletExplosion,
+                EffectType::AllyBulletDespawn,
+            ),
+            ProjectileType::Bullet(Faction::Enemy) => (
+                EffectType::EnemyBulletExplosion,
+                EffectType::EnemyBulletDespawn,
+            ),
+            _ => return None,
+        };
+        Some(ProjectileEffects { impact, despawn })
+    }
+}
+
 #[derive(Component)]
 pub struct AoeDamage {
     pub damage: Damage,
@@ -87,14 +132,26 @@ pub(super) fn plugin(app: &mut App) {
 pub fn bullet_system(
     mut commands: Commands,
     time: Res<Time>,
-    mut query: Query<(&mut Bullet, Entity, &Transform, &Owner, Option<&AoeDamage>), With<Bullet>>,
+    effect_assets: Res<EffectAssets>,
+    mut query: Query<
+        (
+            &mut Bullet,
+            Entity,
+            &Transform,
+            &Owner,
+            Option<&AoeDamage>,
+            Option<&ProjectileEffects>,
+            Option<&DamageSource>,
+        ),
+        With<Bullet>,
+    >,
     potential_query: Query<
         (&Collider, &Transform, Entity),
         (Without<Bullet>, With<Collider>, With<Health>),
     >,
     mut take_damage_event: EventWriter<TakeDamageEvent>,
 ) {
-    for (mut bullet, entity, transform, owner, aoe_damage) in &mut query {
+    for (mut bullet, entity, transform, owner, aoe_damage, effects, damage_source) in &mut query {
         bullet.time2live.tick(time.delta());
         if bullet.time2live.just_finished() {
             // If timed out Aoe damage should still occur
@@ -106,10 +163,21 @@ pub fn bullet_system(
                 do_aoe_damage(
                     potentials,
                     (&mut bullet, transform, aoe_damage),
+                    damage_source.copied(),
                     &mut take_damage_event,
                 );
             }
 
+            if let Some(effects) = effects {
+                spawn_effect(
+                    &mut commands,
+                    &effect_assets,
+                    &effects.despawn,
+                    transform.translation.truncate(),
+                    None,
+                );
+            }
+
             commands.entity(entity).insert(ShouldDespawn);
         }
     }
@@ -125,6 +193,8 @@ pub fn bullet_collision_system(
             &Owner,
             Option<&DirectDamage>,
             Option<&AoeDamage>,
+            Option<&ProjectileEffects>,
+            Option<&DamageSource>,
             &mut Bullet,
         ),
         (
@@ -138,9 +208,23 @@ pub fn bullet_collision_system(
         (&Collider, &Transform, Entity),
         (Without<Bullet>, With<Collider>, With<Health>),
     >,
+    effect_assets: Res<EffectAssets>,
     mut take_damage_event: EventWriter<TakeDamageEvent>,
 ) {
-    for (collider, transform, entity, owner, direct_damage, aoe_damage, mut bullet) in &mut query {
+    for (
+        collider,
+        transform,
+        entity,
+        owner,
+        direct_damage,
+        aoe_damage,
+        effects,
+        damage_source,
+        mut bullet,
+    ) in &mut query
+    {
+        let source = damage_source.copied();
+
         // Get all potentials
         let potentials = potential_query
             .iter()
@@ -165,6 +249,7 @@ pub fn bullet_collision_system(
                 take_damage_event.send(TakeDamageEvent {
                     entity: *potential_entity,
                     damage: direct_damage.0,
+                    source,
                 });
             }
 
@@ -172,10 +257,21 @@ pub fn bullet_collision_system(
                 do_aoe_damage(
                     potentials,
                     (&mut bullet, transform, aoe_damage),
+                    source,
                     &mut take_damage_event,
                 );
             }
 
+            if let Some(effects) = effects {
+                spawn_effect(
+                    &mut commands,
+                    &effect_assets,
+                    &effects.impact,
+                    transform.translation.truncate(),
+                    None,
+                );
+            }
+
             if bullet.despawn_on_hit {
                 commands.entity(entity).insert(ShouldDespawn);
             }
@@ -187,6 +283,7 @@ pub fn bullet_collision_system(
 fn do_aoe_damage(
     potentials: Vec<(&Collider, &Transform, Entity)>,
     bullet: (&mut Bullet, &Transform, &AoeDamage),
+    source: Option<DamageSource>,
     take_damage_event: &mut EventWriter<TakeDamageEvent>,
 ) {
     let (bullet, transform, aoe_damage) = bullet;
@@ -208,24 +305,118 @@ fn do_aoe_damage(
         take_damage_event.send(TakeDamageEvent {
             entity: h.2,
             damage: aoe_damage.damage,
+            source,
         });
     }
 }
 
+/// Draws a projectile with its sprite art when there is some, falling back to a text glyph
+pub fn insert_projectile_visual(
+    entity: &mut EntityCommands,
+    sprite: Option<Sprite>,
+    translation: Vec3,
+    glyph: &str,
+    font: Handle<Font>,
+    font_size: f32,
+    colour: Color,
+) {
+    match sprite {
+        Some(sprite) => {
+            entity.insert((
+                sprite,
+                Transform::from_translation(translation)
+                    .with_scale(Vec3::splat(PROJECTILE_SPRITE_SCALE)),
+            ));
+        }
+        None => {
+            entity.insert((
+                Text2d::new(glyph),
+                TextFont {
+                    font,
+                    font_size,
+                    ..default()
+                },
+                TextColor::from(colour),
+                TextLayout {
+                    justify: JustifyText::Center,
+                    ..default()
+                },
+                Transform::from_translation(translation),
+            ));
+        }
+    }
+}
+
+/// Draws a laser between two points, stretching the beam sprite when there is one and using a
+/// vector line otherwise
+pub fn spawn_laser(
+    commands: &mut Commands,
+    sprite: Option<Sprite>,
+    origin: Vec2,
+    end: Vec2,
+    width: f32,
+    seconds_to_live: f32,
+    colour: Color,
+    owner: Entity,
+) {
+    let mut laser = commands.spawn((
+        Bullet::new(seconds_to_live),
+        LaserRender,
+        Owner(owner),
+        DespawnWithScene,
+    ));
+    // Both graphics start at the origin of the laser, which is also where it is heard from
+    let beam = end - origin;
+    let transform = Transform::from_translation(origin.extend(RenderLayer::Bullet.as_z()));
+    match sprite {
+        Some(sprite) => {
+            laser.insert((
+                Sprite {
+                    custom_size: Some(Vec2::new(beam.length(), width * PROJECTILE_SPRITE_SCALE)),
+                    anchor: Anchor::CenterLeft,
+                    ..sprite
+                },
+                transform.with_rotation(Math::quaternion_from_2d_vector(beam)),
+            ));
+        }
+        None => {
+            laser.insert((
+                ShapeBundle {
+                    path: GeometryBuilder::build_as(&shapes::Line(Vec2::ZERO, beam)),
+                    transform,
+                    ..default()
+                },
+                Stroke::new(colour, width),
+            ));
+        }
+    }
+}
+
 pub fn laser_render_system(
-    mut query: Query<(&Bullet, &mut Stroke), (With<LaserRender>, With<Bullet>, With<Stroke>)>,
-    sound_assets: Res<AudioAssets>,
-    audio: Res<Audio>,
-    config: Res<GameConfig>,
+    mut query: Query<(
+        &Bullet,
+        &Transform,
+        Option<&mut Stroke>,
+        Option<&mut Sprite>,
+        Ref<LaserRender>,
+    )>,
+    mut sound_event: EventWriter<PlaySoundEffectEvent>,
 ) {
-    for (bullet, mut stroke) in &mut query {
-        stroke
-            .color
-            .set_alpha(bullet.time2live.fraction_remaining());
-        //播放laser音效
-        audio
-            .play(sound_assets.laser1.clone())
-            .with_volume(Volume::Amplitude(config.sfx_volume as f64));
+    for (bullet, transform, stroke, sprite, laser) in &mut query {
+        let alpha = bullet.time2live.fraction_remaining();
+        if let Some(mut stroke) = stroke {
+            stroke.color.set_alpha(alpha);
+        }
+        if let Some(mut sprite) = sprite {
+            sprite.color.set_alpha(alpha);
+        }
+        //激光出现时播放一次laser音效
+        if laser.is_added() {
+            sound_event.send(PlaySoundEffectEvent::at(
+                SoundEffectType::Laser,
+                transform.translation.truncate(),
+            ));
+        }
     }
 }
 
@@ -236,9 +427,7 @@ pub fn explosion_render_system(
         (&mut ExplosionRender, &mut Path, Entity, &mut Stroke),
         Without<ShouldDespawn>,
     >,
-    sound_assets: Res<AudioAssets>,
-    audio: Res<Audio>,
-    config: Res<GameConfig>,
+    mut sound_event: EventWriter<PlaySoundEffectEvent>,
 ) {
     for (mut explosion, mut path, entity, mut stroke) in &mut query {
         explosion.ttl.tick(time.delta());
@@ -255,10 +444,10 @@ pub fn explosion_render_system(
 
         if explosion.ttl.finished() {
             //播放爆炸音效
-            audio
-                .play(sound_assets.big_explosion.clone())
-                .with_volume(Volume::Amplitude(config.sfx_volume as f64))
-                .handle();
+            sound_event.send(PlaySoundEffectEvent::at(
+                SoundEffectType::BigExplosion,
+                explosion.origin,
+            ));
             commands.entity(entity).insert(ShouldDespawn);
         }
     }
@@ -1,19 +1,25 @@
-use crate::assets::audio_assets::{AudioAssets, Fonts};
+use crate::assets::audio_assets::Fonts;
+use crate::assets::projectile::ProjectileAssets;
+use crate::components::audio::{PlaySoundEffectEvent, SoundEffectType};
 use crate::components::health::{Health, Owner, Seeker};
-use crate::config::GameConfig;
+use crate::components::spawnable::{Faction, ProjectileType};
+use crate::components::weapon::{ArcPatternData, FireMode, RandomPatternData, SpreadPattern};
+use crate::gameplay::adaptive::AdaptiveDifficulty;
 use crate::gameplay::gamelogic::{
     game_not_paused, Damage, DespawnWithScene, ExplodesOnDespawn, TakeDamageEvent, Targettable,
     WillTarget,
 };
 use crate::gameplay::physics::{BaseRotation, Collider, Physics};
+use crate::gameplay::player::PlayerComponent;
 use crate::screens::AppStates;
 use crate::ship::bullet::{
-    AoeDamage, Bullet, DirectDamage, ExpandingCollider, ExplosionRender, LaserRender,
+    insert_projectile_visual, spawn_laser, AoeDamage, Bullet, DamageSource, DirectDamage,
+    ExpandingCollider, ExplosionRender, ProjectileEffects,
 };
 use crate::ship::engine::Engine;
+use crate::theme::language::LocalizeKey;
 use crate::util::{Colour, Math, RenderLayer};
 use bevy::prelude::*;
-use bevy_kira_audio::prelude::*;
 use bevy_prototype_lyon::prelude::*;
 use rand::distr::Standard;
 use rand::prelude::*;
@@ -35,6 +41,8 @@ impl Default for Range {
 pub struct FireRate {
     pub rate: f32,
     pub timer: Timer,
+    /// A manual turret keeps its shot here until the ship asks it to fire
+    loaded: bool,
 }
 
 impl FireRate {
@@ -42,9 +50,36 @@ impl FireRate {
         FireRate {
             rate,
             timer: Timer::from_seconds(1.0 / rate, TimerMode::Repeating),
+            loaded: false,
         }
     }
 
+    /// Advances the reload timer and returns true if the turret fires this frame.
+    /// Automatic turrets fire whenever they reload, manual ones wait until `triggered`.
+    pub fn fire(&mut self, delta: Duration, mode: FireMode, triggered: bool) -> bool {
+        match mode {
+            FireMode::Automatic => {
+                self.timer.tick(delta);
+                self.timer.just_finished()
+            }
+            FireMode::Manual => {
+                if !self.loaded {
+                    self.timer.tick(delta);
+                    self.loaded = self.timer.just_finished();
+                }
+                let fired = self.loaded && triggered;
+                self.loaded &= !fired;
+                fired
+            }
+        }
+    }
+
+    /// Starts reloading from scratch, dropping a held shot
+    pub fn reset(&mut self) {
+        self.timer.reset();
+        self.loaded = false;
+    }
+
     pub fn set_rate_in_seconds(&mut self, rate: f32) {
         self.rate = rate;
         self.timer.set_duration(Duration::from_secs_f32(1.0 / rate));
@@ -84,6 +119,21 @@ impl std::fmt::Display for TurretClass {
     }
 }
 
+impl LocalizeKey for TurretClass {
+    fn localize_key(&self) -> &'static str {
+        match self {
+            TurretClass::AutoCannon => "turret.auto_cannon",
+            TurretClass::BlastLaser => "turret.blast_laser",
+            TurretClass::RocketLauncher => "turret.rocket_launcher",
+            TurretClass::MineLauncher => "turret.mine_launcher",
+            TurretClass::ShrapnelCannon => "turret.shrapnel_cannon",
+            TurretClass::ChainLaser => "turret.chain_laser",
+            TurretClass::PierceLaser => "turret.pierce_laser",
+            TurretClass::Emp => "turret.emp",
+        }
+    }
+}
+
 impl Distribution<TurretClass> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> TurretClass {
         match rng.gen_range(0..8) {
@@ -99,10 +149,23 @@ impl Distribution<TurretClass> for Standard {
     }
 }
 
-#[derive(Component, Default)]
+#[derive(Component)]
 pub struct DoesDamage {
     pub amount: usize,
     pub crit_chance: f32,
+    /// Scales every hit, e.g. by the difficulty. Fractions round up at random, so even a
+    /// 1-damage weapon deals half its damage on average at 0.5.
+    pub multiplier: f32,
+}
+
+impl Default for DoesDamage {
+    fn default() -> Self {
+        Self {
+            amount: 0,
+            crit_chance: 0.0,
+            multiplier: 1.0,
+        }
+    }
 }
 
 impl DoesDamage {
@@ -114,17 +177,25 @@ impl DoesDamage {
     }
 
     pub fn roll(&self) -> Damage {
-        if thread_rng().gen_range(0.0..1.0) < self.crit_chance {
-            return Damage {
-                amount: self.amount * 2,
-                is_crit: true,
-            };
-        }
+        let mut rng = thread_rng();
+        let is_crit = rng.gen_range(0.0..1.0) < self.crit_chance;
+        let amount = if is_crit {
+            self.amount * 2
+        } else {
+            self.amount
+        };
         Damage {
-            amount: self.amount,
-            is_crit: false,
+            amount: self.scaled(amount, rng.gen_range(0.0..1.0)),
+            is_crit,
         }
     }
+
+    /// Applies the multiplier, the fraction is added when `roll` in `0..1` falls below it
+    pub fn scaled(&self, amount: usize, roll: f32) -> usize {
+        let scaled = amount as f32 * self.multiplier;
+        let whole = scaled.floor();
+        whole as usize + usize::from(roll < scaled - whole)
+    }
 }
 
 #[derive(Component)]
@@ -141,6 +212,68 @@ impl Default for MultiShot {
     }
 }
 
+/// How the projectiles of a single shot are spread out
+#[derive(Component, Clone)]
+pub struct Spread(pub SpreadPattern);
+
+impl Spread {
+    /// Cone of bullets with varying speeds
+    pub fn shrapnel() -> Spread {
+        Spread(SpreadPattern::Random(RandomPatternData {
+            speed_range: 0.67..1.33,
+            angle_range: 0.75..1.25,
+        }))
+    }
+
+    /// Launches rockets in any direction, they steer towards the target afterwards
+    pub fn rockets() -> Spread {
+        Spread(SpreadPattern::Random(RandomPatternData {
+            speed_range: 1.0..1.0,
+            angle_range: 0.0..4.0,
+        }))
+    }
+}
+
+impl Default for Spread {
+    fn default() -> Self {
+        Spread(SpreadPattern::Arc(ArcPatternData {
+            spread_weights: Vec2::ONE,
+            max_spread: PI / 6.0,
+            projectile_gap: PI / 12.0,
+        }))
+    }
+}
+
+/// Which sprite art the projectiles of a turret use, the faction comes from the ship carrying it
+#[derive(Component, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProjectileArt {
+    /// Drawn with text glyphs and vector shapes
+    #[default]
+    None,
+    Bullet,
+    Blast,
+    Beam,
+}
+
+impl ProjectileArt {
+    pub fn projectile_type(&self, faction: Faction) -> Option<ProjectileType> {
+        match self {
+            ProjectileArt::None => None,
+            ProjectileArt::Bullet => Some(ProjectileType::Bullet(faction)),
+            ProjectileArt::Blast => Some(ProjectileType::Blast(faction)),
+            ProjectileArt::Beam => Some(ProjectileType::Beam(faction)),
+        }
+    }
+
+    pub fn sprite(&self, assets: &ProjectileAssets, faction: Faction) -> Option<Sprite> {
+        assets.get_sprite(&self.projectile_type(faction)?)
+    }
+
+    pub fn effects(&self, faction: Faction) -> Option<ProjectileEffects> {
+        ProjectileEffects::from_projectile(&self.projectile_type(faction)?)
+    }
+}
+
 #[derive(Component)]
 pub struct EffectColour(pub Color);
 
@@ -154,18 +287,22 @@ impl Default for EffectColour {
 pub struct TurretBundle {
     pub range: Range,
     pub fire_rate: FireRate,
+    pub fire_mode: FireMode,
     pub target: Targets,
     pub class: TurretClass,
     pub damage: DoesDamage,
     pub shots: MultiShot,
     pub size: EffectSize,
     pub colour: EffectColour,
+    pub spread: Spread,
+    pub art: ProjectileArt,
 }
 
 impl TurretBundle {
     pub fn auto_cannon() -> TurretBundle {
         TurretBundle {
             class: TurretClass::AutoCannon,
+            art: ProjectileArt::Bullet,
             fire_rate: FireRate::from_rate_in_seconds(2.0),
             damage: DoesDamage::from_amount(2),
             colour: EffectColour(Colour::PLAYER),
@@ -176,6 +313,7 @@ impl TurretBundle {
     pub fn blast_laser() -> TurretBundle {
         TurretBundle {
             class: TurretClass::BlastLaser,
+            art: ProjectileArt::Beam,
             fire_rate: FireRate::from_rate_in_seconds(1.5),
             damage: DoesDamage::from_amount(1),
             colour: EffectColour(Colour::PINK),
@@ -189,6 +327,7 @@ impl TurretBundle {
             fire_rate: FireRate::from_rate_in_seconds(0.5),
             damage: DoesDamage::from_amount(5),
             colour: EffectColour(Colour::YELLOW),
+            spread: Spread::rockets(),
             ..Default::default()
         }
     }
@@ -208,10 +347,12 @@ impl TurretBundle {
     pub fn shrapnel_cannon() -> TurretBundle {
         TurretBundle {
             class: TurretClass::ShrapnelCannon,
+            art: ProjectileArt::Bullet,
             fire_rate: FireRate::from_rate_in_seconds(0.25),
             damage: DoesDamage::from_amount(2),
             shots: MultiShot { amount: 16 },
             colour: EffectColour(Colour::PLAYER),
+            spread: Spread::shrapnel(),
             ..Default::default()
         }
     }
@@ -219,6 +360,7 @@ impl TurretBundle {
     pub fn chain_laser() -> TurretBundle {
         TurretBundle {
             class: TurretClass::ChainLaser,
+            art: ProjectileArt::Beam,
             fire_rate: FireRate::from_rate_in_seconds(0.4),
             damage: DoesDamage::from_amount(1),
             shots: MultiShot { amount: 3 },
@@ -230,7 +372,10 @@ impl TurretBundle {
     pub fn pierce_laser() -> TurretBundle {
         TurretBundle {
             class: TurretClass::PierceLaser,
+            art: ProjectileArt::Beam,
             fire_rate: FireRate::from_rate_in_seconds(0.15),
+            // 蓄能武器，装填后等玩家按键再发射
+            fire_mode: FireMode::Manual,
             damage: DoesDamage::from_amount(8),
             size: EffectSize(1.0),
             colour: EffectColour(Colour::YELLOW),
@@ -265,6 +410,7 @@ impl TurretBundle {
 
 pub(super) fn plugin(app: &mut App) {
     app.add_event::<TurretFireEvent>()
+        .add_event::<FireWeaponEvent>()
         .add_systems(
             Update,
             (turret_targetting_system, turret_fire_system)
@@ -293,6 +439,12 @@ pub struct TurretFireEvent {
     pub turret: Entity,
 }
 
+/// Asks every manual turret of a ship to fire once it is reloaded
+#[derive(Event)]
+pub struct FireWeaponEvent {
+    pub ship: Entity,
+}
+
 pub fn get_closest_target(
     potentials: &mut [(Entity, &Transform, &Targettable)],
     point: Vec2,
@@ -355,10 +507,19 @@ fn turret_targetting_system(
 fn turret_fire_system(
     mut commands: Commands,
     time: Res<Time>,
-    mut query: Query<(&mut FireRate, &TurretClass, &mut Targets, Entity)>,
+    mut query: Query<(
+        &mut FireRate,
+        &FireMode,
+        &TurretClass,
+        &mut Targets,
+        &Parent,
+        Entity,
+    )>,
+    mut trigger_event: EventReader<FireWeaponEvent>,
     mut fire_event: EventWriter<TurretFireEvent>,
 ) {
-    for (mut fire_rate, class, mut targets, entity) in &mut query {
+    let triggered: Vec<Entity> = trigger_event.read().map(|ev| ev.ship).collect();
+    for (mut fire_rate, mode, class, mut targets, parent, entity) in &mut query {
         if let Some(target) = targets.target {
             // Check target still exists and if not clear it
 
@@ -367,8 +528,7 @@ fn turret_fire_system(
                 break;
             }
 
-            fire_rate.timer.tick(time.delta());
-            if fire_rate.timer.just_finished() {
+            if fire_rate.fire(time.delta(), *mode, triggered.contains(&parent.get())) {
                 // Fire!
                 fire_event.send(TurretFireEvent {
                     class: *class,
@@ -376,23 +536,41 @@ fn turret_fire_system(
                 });
             }
         } else {
-            fire_rate.timer.reset();
+            fire_rate.reset();
         }
     }
 }
 
+/// Angle in radians a shot is turned off target by. Only enemy shots at the player miss, when the
+/// adaptive difficulty lowers their accuracy.
+fn aim_error(adaptive: &AdaptiveDifficulty, at_player: bool) -> f32 {
+    if at_player {
+        adaptive.aim_error(&mut thread_rng())
+    } else {
+        0.0
+    }
+}
+
 pub fn fire_blast_laser(
     mut commands: Commands,
     mut fire_event: EventReader<TurretFireEvent>,
-    turret_query: Query<(&Parent, &Targets, &DoesDamage, &EffectColour)>,
-    parent_query: Query<&Transform>,
-    target_query: Query<&Transform>,
+    turret_query: Query<(
+        &Parent,
+        &Targets,
+        &DoesDamage,
+        &EffectColour,
+        &ProjectileArt,
+    )>,
+    parent_query: Query<(&Transform, &Targettable)>,
+    target_query: Query<(&Transform, Has<PlayerComponent>)>,
+    projectile_assets: Res<ProjectileAssets>,
     mut take_damage_event: EventWriter<TakeDamageEvent>,
+    adaptive: Res<AdaptiveDifficulty>,
 ) {
     for ev in fire_event.read() {
         if ev.class == TurretClass::BlastLaser {
             // Get Turret Info
-            let Ok((parent, targets, damage, colour)) = turret_query.get(ev.turret) else {
+            let Ok((parent, targets, damage, colour, art)) = turret_query.get(ev.turret) else {
                 continue;
             };
 
@@ -402,36 +580,41 @@ pub fn fire_blast_laser(
             };
 
             // Get Target Info
-            let Ok(target_transform) = target_query.get(target) else {
+            let Ok((target_transform, at_player)) = target_query.get(target) else {
                 continue;
             };
 
             // Get Parent Info
-            let Ok(parent_transform) = parent_query.get(parent.get()) else {
+            let Ok((parent_transform, parent_targettable)) = parent_query.get(parent.get()) else {
                 continue;
             };
 
             // Spawn graphic
             let origin = parent_transform.translation.truncate();
             let target_pos = target_transform.translation.truncate();
-            commands.spawn((
-                Bullet::new(0.1),
-                LaserRender,
-                ShapeBundle {
-                    path: GeometryBuilder::build_as(&shapes::Line(origin, target_pos)),
-                    transform: Transform::from_xyz(0., 0., RenderLayer::Bullet.as_z()),
-                    ..default()
-                },
-                Stroke::new(colour.0, 1.0),
-                Owner(parent.get()),
-                DespawnWithScene,
-            ));
+            let error = aim_error(&adaptive, at_player);
+            spawn_laser(
+                &mut commands,
+                art.sprite(&projectile_assets, parent_targettable.faction()),
+                origin,
+                origin + Vec2::from_angle(error).rotate(target_pos - origin),
+                1.0,
+                0.1,
+                colour.0,
+                parent.get(),
+            );
 
-            // Immediate hit
-            take_damage_event.send(TakeDamageEvent {
-                entity: target,
-                damage: damage.roll(),
-            });
+            // Immediate hit, unless aimed off target
+            if error == 0.0 {
+                take_damage_event.send(TakeDamageEvent {
+                    entity: target,
+                    damage: damage.roll(),
+                    source: Some(DamageSource {
+                        class: ev.class,
+                        ship: parent.get(),
+                    }),
+                });
+            }
         }
     }
 }
@@ -439,15 +622,27 @@ pub fn fire_blast_laser(
 pub fn fire_auto_cannon(
     mut commands: Commands,
     mut fire_event: EventReader<TurretFireEvent>,
-    turret_query: Query<(&Parent, &Targets, &DoesDamage, &EffectColour)>,
-    parent_query: Query<&Transform>,
-    target_query: Query<&Transform>,
+    turret_query: Query<(
+        &Parent,
+        &Targets,
+        &DoesDamage,
+        &MultiShot,
+        &Spread,
+        &EffectColour,
+        &ProjectileArt,
+    )>,
+    parent_query: Query<(&Transform, &Targettable)>,
+    target_query: Query<(&Transform, Has<PlayerComponent>)>,
     fonts: Res<Fonts>,
+    projectile_assets: Res<ProjectileAssets>,
+    adaptive: Res<AdaptiveDifficulty>,
 ) {
     for ev in fire_event.read() {
         if ev.class == TurretClass::AutoCannon {
             // Get Turret Info
-            let Ok((parent, targets, damage, colour)) = turret_query.get(ev.turret) else {
+            let Ok((parent, targets, damage, shots, spread, colour, art)) =
+                turret_query.get(ev.turret)
+            else {
                 continue;
             };
 
@@ -457,33 +652,45 @@ pub fn fire_auto_cannon(
             };
 
             // Get Target Info
-            let Ok(target_transform) = target_query.get(target) else {
+            let Ok((target_transform, at_player)) = target_query.get(target) else {
                 continue;
             };
 
             // Get Parent Info
-            let Ok(parent_transform) = parent_query.get(parent.get()) else {
+            let Ok((parent_transform, parent_targettable)) = parent_query.get(parent.get()) else {
                 continue;
             };
+            let sprite = art.sprite(&projectile_assets, parent_targettable.faction());
+            let effects = art.effects(parent_targettable.faction());
 
-            // Spawn bullet
+            // Spawn bullets
             let bullet_speed = 1000.0;
             let origin = parent_transform.translation.truncate();
             let destination = target_transform.translation.truncate();
-            let direction = (destination - origin).normalize();
-            spawn_bullet(
-                &mut commands,
-                fonts.primary.clone(),
-                colour,
-                origin.extend(RenderLayer::Bullet.as_z()),
-                direction * bullet_speed,
-                parent,
-                damage,
-                &".".to_string(),
-                1.2,
-                16.0,
-                5.0,
-            );
+            let direction = Vec2::from_angle(aim_error(&adaptive, at_player))
+                .rotate((destination - origin).normalize());
+            for velocity in
+                spread
+                    .0
+                    .velocities_towards(direction, bullet_speed, shots.amount as usize)
+            {
+                spawn_bullet(
+                    &mut commands,
+                    sprite.clone(),
+                    effects.clone(),
+                    fonts.primary.clone(),
+                    colour,
+                    origin.extend(RenderLayer::Bullet.as_z()),
+                    velocity,
+                    parent,
+                    damage,
+                    ev.class,
+                    &".".to_string(),
+                    1.2,
+                    16.0,
+                    5.0,
+                );
+            }
         }
     }
 }
@@ -491,6 +698,7 @@ pub fn fire_auto_cannon(
 fn spawn_link(
     commands: &mut Commands,
     take_damage_event: &mut EventWriter<TakeDamageEvent>,
+    sprite: Option<Sprite>,
     target_position: Vec2,
     origin: Vec2,
     target: Entity,
@@ -500,22 +708,24 @@ fn spawn_link(
     owner: Entity,
 ) -> Option<Vec2> {
     // Spawn graphic
-    commands.spawn((
-        Bullet::new(0.2 + (jump as f32) * 0.1),
-        LaserRender,
-        ShapeBundle {
-            path: GeometryBuilder::build_as(&shapes::Line(origin, target_position)),
-            transform: Transform::from_xyz(0., 0., RenderLayer::Bullet.as_z()),
-            ..default()
-        },
-        Stroke::new(colour.0, 2.0),
-        Owner(owner),
-        DespawnWithScene,
-    ));
+    spawn_laser(
+        commands,
+        sprite,
+        origin,
+        target_position,
+        2.0,
+        0.2 + (jump as f32) * 0.1,
+        colour.0,
+        owner,
+    );
     // Immediate hit
     take_damage_event.send(TakeDamageEvent {
         entity: target,
         damage: damage.roll(),
+        source: Some(DamageSource {
+            class: TurretClass::ChainLaser,
+            ship: owner,
+        }),
     });
     Some(target_position)
 }
@@ -523,19 +733,28 @@ fn spawn_link(
 pub fn fire_chain_laser(
     mut commands: Commands,
     mut fire_event: EventReader<TurretFireEvent>,
-    turret_query: Query<(&Parent, &Targets, &DoesDamage, &MultiShot, &EffectColour)>,
-    parent_query: Query<(&Transform, &WillTarget)>,
+    turret_query: Query<(
+        &Parent,
+        &Targets,
+        &DoesDamage,
+        &MultiShot,
+        &EffectColour,
+        &ProjectileArt,
+    )>,
+    parent_query: Query<(&Transform, &WillTarget, &Targettable)>,
     target_query: Query<&Transform>,
     potential_query: Query<
         (Entity, &Transform, &Targettable),
         (With<Targettable>, With<Transform>),
     >,
+    projectile_assets: Res<ProjectileAssets>,
     mut take_damage_event: EventWriter<TakeDamageEvent>,
 ) {
     for ev in fire_event.read() {
         if ev.class == TurretClass::ChainLaser {
             // Get Turret Info
-            let Ok((parent, targets, damage, shots, colour)) = turret_query.get(ev.turret) else {
+            let Ok((parent, targets, damage, shots, colour, art)) = turret_query.get(ev.turret)
+            else {
                 continue;
             };
 
@@ -545,9 +764,12 @@ pub fn fire_chain_laser(
             };
 
             // Get Parent Info
-            let Ok((parent_transform, parent_will_target)) = parent_query.get(parent.get()) else {
+            let Ok((parent_transform, parent_will_target, parent_targettable)) =
+                parent_query.get(parent.get())
+            else {
                 continue;
             };
+            let sprite = art.sprite(&projectile_assets, parent_targettable.faction());
 
             // Get all possible targets
             let mut potential_targets: Vec<(Entity, &Transform, &Targettable)> = potential_query
@@ -573,6 +795,7 @@ pub fn fire_chain_laser(
                 let result = spawn_link(
                     &mut commands,
                     &mut take_damage_event,
+                    sprite.clone(),
                     target_query.get(target).unwrap().translation.truncate(),
                     previous_position,
                     target,
@@ -644,6 +867,10 @@ pub fn fire_emp(
                     final_radius: size.0,
                 },
                 DirectDamage(damage.roll()),
+                DamageSource {
+                    class: ev.class,
+                    ship: parent.get(),
+                },
                 Owner(parent.get()),
             ));
         }
@@ -656,9 +883,7 @@ pub fn fire_mine_launcher(
     turret_query: Query<(&Parent, &DoesDamage, &EffectSize, &EffectColour, &MultiShot)>,
     parent_query: Query<&Transform>,
     fonts: Res<Fonts>,
-    sound_assets: Res<AudioAssets>,
-    audio: Res<Audio>,
-    config: Res<GameConfig>,
+    mut sound_event: EventWriter<PlaySoundEffectEvent>,
 ) {
     for ev in fire_event.read() {
         if ev.class == TurretClass::MineLauncher {
@@ -675,9 +900,10 @@ pub fn fire_mine_launcher(
             // Spawn mine
             let origin = parent_transform.translation.truncate();
             //播放音效
-            audio
-                .play(sound_assets.zap.clone())
-                .with_volume(Volume::Amplitude(config.sfx_volume as f64));
+            sound_event.send(PlaySoundEffectEvent::at(
+                SoundEffectType::MineLaunch,
+                origin,
+            ));
             commands.spawn((
                 Bullet::new(30.0),
                 Text2d::new("¤"),
@@ -708,6 +934,10 @@ pub fn fire_mine_launcher(
                     damage: damage.roll(),
                     range: size.0,
                 },
+                DamageSource {
+                    class: ev.class,
+                    ship: parent.get(),
+                },
                 DespawnWithScene,
             ));
         }
@@ -717,16 +947,26 @@ pub fn fire_mine_launcher(
 pub fn fire_pierce_laser(
     mut commands: Commands,
     mut fire_event: EventReader<TurretFireEvent>,
-    turret_query: Query<(&Parent, &Targets, &DoesDamage, &EffectSize, &EffectColour)>,
-    parent_query: Query<(&Transform, &WillTarget)>,
-    target_query: Query<&Transform>,
+    turret_query: Query<(
+        &Parent,
+        &Targets,
+        &DoesDamage,
+        &EffectSize,
+        &EffectColour,
+        &ProjectileArt,
+    )>,
+    parent_query: Query<(&Transform, &WillTarget, &Targettable)>,
+    target_query: Query<(&Transform, Has<PlayerComponent>)>,
     potential_query: Query<(Entity, &Transform, &Targettable, &Collider)>,
+    projectile_assets: Res<ProjectileAssets>,
     mut take_damage_event: EventWriter<TakeDamageEvent>,
+    adaptive: Res<AdaptiveDifficulty>,
 ) {
     for ev in fire_event.read() {
         if ev.class == TurretClass::PierceLaser {
             // Get Turret Info
-            let Ok((parent, targets, damage, size, colour)) = turret_query.get(ev.turret) else {
+            let Ok((parent, targets, damage, size, colour, art)) = turret_query.get(ev.turret)
+            else {
                 continue;
             };
 
@@ -736,12 +976,14 @@ pub fn fire_pierce_laser(
             };
 
             // Get Target Info
-            let Ok(target_transform) = target_query.get(target) else {
+            let Ok((target_transform, at_player)) = target_query.get(target) else {
                 continue;
             };
 
             // Get Parent Info
-            let Ok((parent_transform, parent_will_target)) = parent_query.get(parent.get()) else {
+            let Ok((parent_transform, parent_will_target, parent_targettable)) =
+                parent_query.get(parent.get())
+            else {
                 continue;
             };
 
@@ -749,19 +991,19 @@ pub fn fire_pierce_laser(
             const LASER_LENGTH: f32 = 8000.0;
             let origin = parent_transform.translation.truncate();
             let target = target_transform.translation.truncate();
-            let end = (target - origin).normalize() * LASER_LENGTH;
-            commands.spawn((
-                Bullet::new(1.0),
-                LaserRender,
-                ShapeBundle {
-                    path: GeometryBuilder::build_as(&shapes::Line(origin, end)),
-                    transform: Transform::from_xyz(0., 0., RenderLayer::Bullet.as_z()),
-                    ..default()
-                },
-                Stroke::new(colour.0, size.0),
-                Owner(parent.get()),
-                DespawnWithScene,
-            ));
+            let end = Vec2::from_angle(aim_error(&adaptive, at_player))
+                .rotate((target - origin).normalize())
+                * LASER_LENGTH;
+            spawn_laser(
+                &mut commands,
+                art.sprite(&projectile_assets, parent_targettable.faction()),
+                origin,
+                end,
+                size.0,
+                1.0,
+                colour.0,
+                parent.get(),
+            );
 
             // Hit everything on the path
             let events = potential_query
@@ -774,6 +1016,10 @@ pub fn fire_pierce_laser(
                 .map(|hit| TakeDamageEvent {
                     entity: hit.0,
                     damage: damage.roll(),
+                    source: Some(DamageSource {
+                        class: ev.class,
+                        ship: parent.get(),
+                    }),
                 });
             take_damage_event.send_batch(events);
         }
@@ -783,17 +1029,23 @@ pub fn fire_pierce_laser(
 pub fn fire_rocket_launcher(
     mut commands: Commands,
     mut fire_event: EventReader<TurretFireEvent>,
-    turret_query: Query<(&Parent, &Targets, &DoesDamage, &MultiShot, &EffectColour)>,
+    turret_query: Query<(
+        &Parent,
+        &Targets,
+        &DoesDamage,
+        &MultiShot,
+        &Spread,
+        &EffectColour,
+    )>,
     parent_query: Query<&Transform>,
     fonts: Res<Fonts>,
-    sound_assets: Res<AudioAssets>,
-    audio: Res<Audio>,
-    config: Res<GameConfig>,
+    mut sound_event: EventWriter<PlaySoundEffectEvent>,
 ) {
     for ev in fire_event.read() {
         if ev.class == TurretClass::RocketLauncher {
             // Get Turret Info
-            let Ok((parent, targets, damage, shots, colour)) = turret_query.get(ev.turret) else {
+            let Ok((parent, targets, damage, shots, spread, colour)) = turret_query.get(ev.turret)
+            else {
                 continue;
             };
 
@@ -809,11 +1061,16 @@ pub fn fire_rocket_launcher(
 
             // Spawn rocket
             let origin = parent_transform.translation.truncate();
-            for _ in 0..shots.amount {
+            let launch_velocities =
+                spread
+                    .0
+                    .velocities_towards(Vec2::Y, 100.0, shots.amount as usize);
+            for velocity in launch_velocities {
                 //播放音效
-                audio
-                    .play(sound_assets.player_fire.clone())
-                    .with_volume(Volume::Amplitude(config.sfx_volume as f64));
+                sound_event.send(PlaySoundEffectEvent::at(
+                    SoundEffectType::RocketLaunch,
+                    origin,
+                ));
                 commands.spawn((
                     Bullet::new(3.0),
                     Text2d::new("!"),
@@ -832,7 +1089,7 @@ pub fn fire_rocket_launcher(
                         rotation: Quat::from_rotation_z(PI / 2.0),
                     },
                     Physics {
-                        velocity: Math::random_2d_unit_vector() * 100.0,
+                        velocity,
                         ..Default::default()
                     },
                     Engine::new_with_steering(40.0, 10.0, 0.5),
@@ -847,6 +1104,10 @@ pub fn fire_rocket_launcher(
                         damage: damage.roll(),
                         range: 40.0,
                     },
+                    DamageSource {
+                        class: ev.class,
+                        ship: parent.get(),
+                    },
                     DespawnWithScene,
                 ));
             }
@@ -857,15 +1118,26 @@ pub fn fire_rocket_launcher(
 pub fn fire_shrapnel_cannon(
     mut commands: Commands,
     mut fire_event: EventReader<TurretFireEvent>,
-    turret_query: Query<(&Parent, &Targets, &DoesDamage, &MultiShot, &EffectColour)>,
-    parent_query: Query<&Transform>,
+    turret_query: Query<(
+        &Parent,
+        &Targets,
+        &DoesDamage,
+        &MultiShot,
+        &Spread,
+        &EffectColour,
+        &ProjectileArt,
+    )>,
+    parent_query: Query<(&Transform, &Targettable)>,
     target_query: Query<&Transform>,
     fonts: Res<Fonts>,
+    projectile_assets: Res<ProjectileAssets>,
 ) {
     for ev in fire_event.read() {
         if ev.class == TurretClass::ShrapnelCannon {
             // Get Turret Info
-            let Ok((parent, targets, damage, shots, colour)) = turret_query.get(ev.turret) else {
+            let Ok((parent, targets, damage, shots, spread, colour, art)) =
+                turret_query.get(ev.turret)
+            else {
                 continue;
             };
 
@@ -880,33 +1152,34 @@ pub fn fire_shrapnel_cannon(
             };
 
             // Get Parent Info
-            let Ok(parent_transform) = parent_query.get(parent.get()) else {
+            let Ok((parent_transform, parent_targettable)) = parent_query.get(parent.get()) else {
                 continue;
             };
+            let sprite = art.sprite(&projectile_assets, parent_targettable.faction());
+            let effects = art.effects(parent_targettable.faction());
 
             // Spawn bullets
-            const SPREAD: f32 = PI / 4.0;
-            const SPEED_VARIANCE: f32 = 400.0;
-
             let bullet_speed = 600.0;
             let origin = parent_transform.translation.truncate();
             let destination = target_transform.translation.truncate();
             let direction = (destination - origin).normalize();
 
-            let mut rng: ThreadRng = thread_rng();
-            for _ in 0..shots.amount {
-                let random_angle = rng.gen_range(-SPREAD / 2.0..SPREAD / 2.0);
-                let spread_direction = Vec2::from_angle(random_angle).rotate(direction);
-                let random_speed =
-                    rng.gen_range(-SPEED_VARIANCE / 2.0..SPEED_VARIANCE / 2.0) + bullet_speed;
+            for velocity in
+                spread
+                    .0
+                    .velocities_towards(direction, bullet_speed, shots.amount as usize)
+            {
                 spawn_bullet(
                     &mut commands,
+                    sprite.clone(),
+                    effects.clone(),
                     fonts.primary.clone(),
                     colour,
                     origin.extend(RenderLayer::Bullet.as_z()),
-                    spread_direction * random_speed,
+                    velocity,
                     parent,
                     damage,
+                    ev.class,
                     &".".to_string(),
                     1.2,
                     16.0,
@@ -919,31 +1192,22 @@ pub fn fire_shrapnel_cannon(
 
 fn spawn_bullet(
     commands: &mut Commands,
+    sprite: Option<Sprite>,
+    effects: Option<ProjectileEffects>,
     font: Handle<Font>,
     colour: &EffectColour,
     translation: Vec3,
     velocity: Vec2,
     parent: &Parent,
     damage: &DoesDamage,
+    source: TurretClass,
     bullet_text: &String,
     seconds2live: f32,
     font_size: f32,
     radius: f32,
 ) {
-    commands.spawn((
+    let mut bullet = commands.spawn((
         Bullet::new(seconds2live),
-        Text2d::new(bullet_text),
-        TextFont {
-            font,
-            font_size,
-            ..default()
-        },
-        TextColor::from(colour.0),
-        TextLayout {
-            justify: JustifyText::Center,
-            ..default()
-        },
-        Transform::from_translation(translation),
         Physics {
             velocity,
             ..Default::default()
@@ -951,6 +1215,22 @@ fn spawn_bullet(
         Collider { radius },
         Owner(parent.get()),
         DirectDamage(damage.roll()),
+        DamageSource {
+            class: source,
+            ship: parent.get(),
+        },
         DespawnWithScene,
     ));
+    if let Some(effects) = effects {
+        bullet.insert(effects);
+    }
+    insert_projectile_visual(
+        &mut bullet,
+        sprite,
+        translation,
+        bullet_text,
+        font,
+        font_size,
+        colour.0,
+    );
 }
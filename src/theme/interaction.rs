@@ -1,7 +1,6 @@
 use crate::assets::audio_assets::AudioAssets;
-use crate::config::GameConfig;
+use crate::components::audio::{PlaySoundEffectEvent, SoundEffectType};
 use bevy::prelude::*;
-use bevy_kira_audio::prelude::*;
 
 pub(super) fn plugin(app: &mut App) {
     app.register_type::<InteractionPalette>();
@@ -11,7 +10,10 @@ pub(super) fn plugin(app: &mut App) {
             trigger_on_press,
             apply_interaction_palette,
             trigger_interaction_sound_effect,
+            navigate_buttons,
+            apply_focus_palette,
         )
+            .chain()
             .run_if(resource_exists::<AudioAssets>),
     );
 }
@@ -59,20 +61,97 @@ fn apply_interaction_palette(
     }
 }
 
+/// Button picked with the arrow keys or the gamepad's d-pad, pressed with Enter or the south
+/// button. Only buttons with an [`InteractionPalette`] can be focused.
+#[derive(Component)]
+pub struct Focused;
+
+/// Moves the focus between the buttons on screen in reading order
+fn navigate_buttons(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    buttons: Query<(Entity, &GlobalTransform, &InheritedVisibility), With<InteractionPalette>>,
+    focused: Query<Entity, With<Focused>>,
+    mut sound_event: EventWriter<PlaySoundEffectEvent>,
+) {
+    let gamepad_pressed = |button| gamepads.iter().any(|gamepad| gamepad.just_pressed(button));
+    let previous = keyboard.any_just_pressed([KeyCode::ArrowUp, KeyCode::ArrowLeft])
+        || gamepad_pressed(GamepadButton::DPadUp)
+        || gamepad_pressed(GamepadButton::DPadLeft);
+    let next = keyboard.any_just_pressed([KeyCode::ArrowDown, KeyCode::ArrowRight])
+        || gamepad_pressed(GamepadButton::DPadDown)
+        || gamepad_pressed(GamepadButton::DPadRight);
+    let confirm = keyboard.any_just_pressed([KeyCode::Enter, KeyCode::NumpadEnter])
+        || gamepad_pressed(GamepadButton::South);
+
+    let current = focused.get_single().ok();
+    if confirm {
+        if let Some(entity) = current {
+            commands.trigger_targets(OnPress, entity);
+            sound_event.send(PlaySoundEffectEvent::new(SoundEffectType::ButtonPress));
+        }
+        return;
+    }
+    if !previous && !next {
+        return;
+    }
+
+    let mut order = buttons
+        .iter()
+        .filter(|(_, _, visibility)| visibility.get())
+        .map(|(entity, transform, _)| (entity, transform.translation().truncate()))
+        .collect::<Vec<_>>();
+    if order.is_empty() {
+        return;
+    }
+    // UI 坐标 y 轴向下，按行再按列排序
+    order.sort_by(|(_, a), (_, b)| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x)));
+    let index = current
+        .and_then(|entity| order.iter().position(|(candidate, _)| *candidate == entity))
+        .map(|index| {
+            if next {
+                (index + 1) % order.len()
+            } else {
+                (index + order.len() - 1) % order.len()
+            }
+        })
+        .unwrap_or(0);
+
+    for entity in &focused {
+        commands.entity(entity).remove::<Focused>();
+    }
+    commands.entity(order[index].0).insert(Focused);
+    sound_event.send(PlaySoundEffectEvent::new(SoundEffectType::ButtonHover));
+}
+
+fn apply_focus_palette(
+    mut palette_query: Query<(&InteractionPalette, &mut BackgroundColor)>,
+    focused: Query<Entity, Added<Focused>>,
+    mut unfocused: RemovedComponents<Focused>,
+) {
+    for entity in unfocused.read() {
+        if let Ok((palette, mut background)) = palette_query.get_mut(entity) {
+            *background = palette.none.into();
+        }
+    }
+    for entity in &focused {
+        if let Ok((palette, mut background)) = palette_query.get_mut(entity) {
+            *background = palette.hovered.into();
+        }
+    }
+}
+
 fn trigger_interaction_sound_effect(
     interaction_query: Query<&Interaction, Changed<Interaction>>,
-    interaction_assets: Res<AudioAssets>,
-    audio: Res<Audio>,
-    config: Res<GameConfig>,
+    mut sound_event: EventWriter<PlaySoundEffectEvent>,
 ) {
     for interaction in &interaction_query {
-        let source = match interaction {
-            Interaction::Hovered => interaction_assets.hover.clone(),
-            Interaction::Pressed => interaction_assets.press.clone(),
+        let sound_effect_type = match interaction {
+            Interaction::Hovered => SoundEffectType::ButtonHover,
+            Interaction::Pressed => SoundEffectType::ButtonPress,
             _ => continue,
         };
-        audio
-            .play(source)
-            .with_volume(Volume::Amplitude(config.sfx_volume as f64));
+        sound_event.send(PlaySoundEffectEvent::new(sound_effect_type));
     }
 }
@@ -2,15 +2,19 @@ use bevy::{
     asset::{io::Reader, AssetLoader, LoadContext},
     prelude::*,
     reflect::TypePath,
-    utils::{ConditionalSendFuture, HashMap},
+    utils::{ConditionalSendFuture, HashMap, HashSet},
 };
+use std::fmt::{Display, Write};
 use std::future::Future;
+use std::sync::Mutex;
 
 pub(super) fn plugin(app: &mut App) {
     app.register_asset_loader(TranslationsAssetLoader)
         .init_asset::<Translation>()
-        .add_systems(Update, update_text);
-    app.insert_resource(Localize::from_asset_path("configs/duolingo.csv"));
+        .add_systems(Update, (update_text, update_font).chain());
+    app.insert_resource(
+        Localize::from_asset_path("configs/duolingo.csv").with_fallback_language("English"),
+    );
 }
 
 /// You can use this resource in two ways:
@@ -22,11 +26,16 @@ pub(super) fn plugin(app: &mut App) {
 pub struct Localize {
     is_initialized: bool,
     set_language_after_init: Option<String>,
+    set_fallback_language_after_init: Option<String>,
     current_language_id: usize,
+    fallback_language_id: usize,
     languages: HashMap<String, usize>,
     words: HashMap<String, Vec<String>>,
     asset_handle_path: Option<String>,
     asset_handle: Option<Handle<Translation>>,
+    font: Option<Handle<Font>>,
+    font_language_id: Option<usize>,
+    missing_keywords: Mutex<HashSet<String>>,
 }
 impl Localize {
     /// Initializes an empty resource
@@ -34,11 +43,16 @@ impl Localize {
         Self {
             is_initialized: false,
             set_language_after_init: None,
+            set_fallback_language_after_init: None,
             current_language_id: 0,
+            fallback_language_id: 0,
             languages: HashMap::new(),
             words: HashMap::new(),
             asset_handle_path: None,
             asset_handle: None,
+            font: None,
+            font_language_id: None,
+            missing_keywords: Mutex::new(HashSet::new()),
         }
     }
     /// Creates a new resource from specified data (in a .csv format)
@@ -58,6 +72,11 @@ impl Localize {
         self.set_language(language);
         self
     }
+    /// Creates a new resource from `self` with a given fallback language.
+    pub fn with_fallback_language(mut self, language: impl ToString) -> Self {
+        self.set_fallback_language(language);
+        self
+    }
     /// Sets data for the resource
     pub fn set_data(&mut self, translations: &str) {
         let mut languages = HashMap::new();
@@ -81,22 +100,123 @@ impl Localize {
         }
         self.languages = languages;
         self.words = words;
+        self.font_language_id = None;
         self.initialized();
     }
     /// Get a translation for a specified keyword.
+    /// Empty cells fall back to the fallback language (the first language column by default).
     /// If there is no translation for the keyword, it will return an empty string.
     pub fn get(&self, keyword: &str) -> &str {
-        match self.words.get(keyword) {
-            Some(k) => {
-                if self.current_language_id < k.len() {
-                    &k[self.current_language_id]
-                } else {
-                    ""
+        self.record_if_missing(keyword);
+        self.lookup(keyword).unwrap_or("")
+    }
+    /// Get a translation for an enum through its stable keyword.
+    pub fn get_key(&self, item: &impl LocalizeKey) -> &str {
+        self.get(item.localize_key())
+    }
+    /// Get a translation and substitute named arguments, e.g. `{level}` with `("level", &3)`.
+    /// Placeholders without a matching argument are left untouched.
+    pub fn format(&self, keyword: &str, args: &[(&str, &dyn Display)]) -> String {
+        interpolate(self.get(keyword), args)
+    }
+    /// Get the plural form of a translation for `count`, which is also available as `{count}`.
+    /// Looks up `keyword.one` / `keyword.other` by the current language's plural rule,
+    /// then `keyword.other`, then the bare keyword.
+    pub fn plural(&self, keyword: &str, count: usize, args: &[(&str, &dyn Display)]) -> String {
+        let category = plural_category(self.current_language(), count);
+        let template = self
+            .lookup(&format!("{}.{}", keyword, category))
+            .or_else(|| self.lookup(&format!("{}.other", keyword)))
+            .or_else(|| self.lookup(keyword))
+            .unwrap_or_else(|| {
+                self.record_if_missing(&format!("{}.other", keyword));
+                ""
+            });
+        let mut all_args: Vec<(&str, &dyn Display)> = vec![("count", &count)];
+        all_args.extend_from_slice(args);
+        interpolate(template, &all_args)
+    }
+    /// Returns the translation in the current language, or in the fallback language when the
+    /// cell is empty. None if neither has a translation.
+    fn lookup(&self, keyword: &str) -> Option<&str> {
+        let cells = self.words.get(keyword)?;
+        [self.current_language_id, self.fallback_language_id]
+            .into_iter()
+            .filter_map(|language_id| cells.get(language_id))
+            .find(|cell| !cell.is_empty())
+            .map(|cell| cell.as_str())
+    }
+    /// Remembers keywords requested at runtime that have no row in the translations.
+    fn record_if_missing(&self, keyword: &str) {
+        if self.is_initialized && !self.words.contains_key(keyword) {
+            if let Ok(mut missing) = self.missing_keywords.lock() {
+                missing.insert(keyword.to_string());
+            }
+        }
+    }
+    /// Keywords requested at runtime that have no row in the translations, sorted.
+    pub fn missing_keywords(&self) -> Vec<String> {
+        let mut missing: Vec<String> = self
+            .missing_keywords
+            .lock()
+            .map(|missing| missing.iter().cloned().collect())
+            .unwrap_or_default();
+        missing.sort();
+        missing
+    }
+    /// Every empty cell as `(language, keyword)`, sorted by keyword then language.
+    /// `keyword.one` rows are not required for languages without plural forms.
+    pub fn empty_cells(&self) -> Vec<(&str, &str)> {
+        let mut languages: Vec<(&str, usize)> = self
+            .languages
+            .iter()
+            .map(|(language, language_id)| (language.as_str(), *language_id))
+            .collect();
+        languages.sort_by_key(|(_, language_id)| *language_id);
+        let mut keywords: Vec<&String> = self.words.keys().collect();
+        keywords.sort();
+
+        let mut empty = Vec::new();
+        for keyword in keywords {
+            let cells = &self.words[keyword];
+            for (language, language_id) in &languages {
+                if keyword.ends_with(".one") && NO_PLURAL_LANGUAGES.contains(language) {
+                    continue;
+                }
+                if !cells.get(*language_id).is_some_and(|cell| !cell.is_empty()) {
+                    empty.push((*language, keyword.as_str()));
                 }
             }
-            None => "",
+        }
+        empty
+    }
+    pub fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+    fn current_language(&self) -> &str {
+        self.languages
+            .iter()
+            .find(|(_, language_id)| **language_id == self.current_language_id)
+            .map(|(language, _)| language.as_str())
+            .unwrap_or_default()
+    }
+    /// Sets the language used when a cell of the current language is empty.
+    pub fn set_fallback_language(&mut self, language: impl ToString) {
+        let language = language.to_string();
+        if self.is_initialized {
+            if let Some(language_id) = self.languages.get(&language) {
+                self.fallback_language_id = *language_id;
+            } else {
+                error!("Fallback language not found! ({})", language);
+            }
+        } else {
+            self.set_fallback_language_after_init = Some(language);
         }
     }
+    /// Font declared for the current language by the `language.font` row, if any.
+    pub fn font(&self) -> Option<Handle<Font>> {
+        self.font.clone()
+    }
     /// Sets the language for the resource.
     pub fn set_language(&mut self, language: impl ToString) {
         let language = language.to_string();
@@ -115,11 +235,66 @@ impl Localize {
         if let Some(language) = self.set_language_after_init.clone() {
             self.set_language(language);
         }
+        if let Some(language) = self.set_fallback_language_after_init.clone() {
+            self.set_fallback_language(language);
+        }
+    }
+}
+/// Languages without grammatical number always use the `other` plural form.
+const NO_PLURAL_LANGUAGES: [&str; 3] = ["Chinese", "Japanese", "Korean"];
+
+fn plural_category(language: &str, count: usize) -> &'static str {
+    if count == 1 && !NO_PLURAL_LANGUAGES.contains(&language) {
+        "one"
+    } else {
+        "other"
+    }
+}
+
+/// Replaces every `{name}` placeholder with the matching argument.
+fn interpolate(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}').map(|end| start + end) else {
+            break;
+        };
+        result.push_str(&rest[..start]);
+        let name = &rest[start + 1..end];
+        match args.iter().find(|(arg, _)| *arg == name) {
+            Some((_, value)) => {
+                let _ = write!(result, "{}", value);
+            }
+            None => result.push_str(&rest[start..=end]),
+        }
+        rest = &rest[end + 1..];
     }
+    result.push_str(rest);
+    result
+}
+
+/// Stable keyword for enums shown in the UI, so translations don't depend on `Display` text.
+pub trait LocalizeKey {
+    fn localize_key(&self) -> &'static str;
 }
+
+/// Keyword whose cells hold the font asset path used by each language.
+const FONT_KEYWORD: &str = "language.font";
+
+/// Swaps the `TextFont` of the entity to the current language's font.
+/// Added automatically with `LocalizeText`, insert it on other text that shows translations.
+#[derive(Component, Default)]
+pub struct LocalizeFont;
+
+/// Keeps the font the text, or the text of a button, was spawned with.
+/// For text mixing scripts, like the language toggle showing "中文" in English.
+#[derive(Component, Default)]
+pub struct FixedFont;
+
 /// Translates text.
 /// Use it with the `Text` component.
 #[derive(Component)]
+#[require(LocalizeFont)]
 pub struct LocalizeText {
     sections: Vec<String>,
     translated_language: Option<usize>,
@@ -168,6 +343,13 @@ fn update_text(
             }
         }
         if localize.is_initialized {
+            if localize.font_language_id != Some(localize.current_language_id) {
+                let font = localize
+                    .lookup(FONT_KEYWORD)
+                    .map(|path| asset_server.load(path.to_string()));
+                localize.font = font;
+                localize.font_language_id = Some(localize.current_language_id);
+            }
             // info!("is_initialized!");
             for (entity, mut localize_text) in &mut text {
                 if localize_text.translated_language.is_none()
@@ -187,6 +369,27 @@ fn update_text(
     }
 }
 
+fn update_font(
+    localize: Option<Res<Localize>>,
+    mut text_fonts: Query<
+        (&mut TextFont, Option<&Parent>),
+        (With<LocalizeFont>, Without<FixedFont>),
+    >,
+    fixed: Query<(), With<FixedFont>>,
+) {
+    let Some(font) = localize.and_then(|localize| localize.font()) else {
+        return;
+    };
+    for (mut text_font, parent) in &mut text_fonts {
+        if parent.is_some_and(|parent| fixed.contains(parent.get())) {
+            continue;
+        }
+        if text_font.font != font {
+            text_font.font = font.clone();
+        }
+    }
+}
+
 #[derive(Asset, TypePath, Debug)]
 pub struct Translation(pub String);
 #[derive(Default)]
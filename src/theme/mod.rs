@@ -10,8 +10,8 @@ mod widgets;
 #[allow(unused_imports)]
 pub mod prelude {
     pub use super::{
-        interaction::{InteractionPalette, OnPress},
-        language::{Localize, LocalizeText},
+        interaction::{Focused, InteractionPalette, OnPress},
+        language::{FixedFont, Localize, LocalizeFont, LocalizeKey, LocalizeText},
         palette as ui_palette,
         widgets::{Containers as _, Widgets as _},
     };
@@ -9,7 +9,11 @@ impl Math {
     }
 
     pub fn random_2d_unit_vector() -> Vec2 {
-        let mut rng = rand::thread_rng();
+        Math::random_2d_unit_vector_from(&mut rand::thread_rng())
+    }
+
+    /// Same as [`Math::random_2d_unit_vector`], drawn from `rng` so seeded runs can repeat it
+    pub fn random_2d_unit_vector_from(rng: &mut impl Rng) -> Vec2 {
         Vec2 {
             x: rng.gen_range(-1.0..1.0),
             y: rng.gen_range(-1.0..1.0),
@@ -0,0 +1,51 @@
+use bevy::asset::ron::de::from_bytes;
+use skywalker2088::achievement::{AchievementCondition, AchievementProgress, AchievementsResource};
+use skywalker2088::theme::language::Localize;
+
+const ACHIEVEMENTS: &[u8] = include_bytes!("../assets/data/achievements.ron");
+const TRANSLATIONS: &str = include_str!("../assets/configs/duolingo.csv");
+
+fn kill(progress: &mut AchievementProgress, achievements: &AchievementsResource) -> Vec<String> {
+    progress
+        .update(achievements, |condition, current| match condition {
+            AchievementCondition::Kills(_) => current + 1,
+            _ => current,
+        })
+        .into_iter()
+        .map(|achievement| achievement.id)
+        .collect()
+}
+
+#[test]
+fn every_achievement_is_translated() {
+    let achievements = from_bytes::<AchievementsResource>(ACHIEVEMENTS).unwrap();
+    let localize = Localize::from_data(TRANSLATIONS);
+    for achievement in &achievements.achievements {
+        localize.get(&achievement.name_key());
+        localize.get(&achievement.description_key());
+    }
+    assert_eq!(localize.missing_keywords(), Vec::<String>::new());
+}
+
+#[test]
+fn achievements_unlock_once_when_reaching_their_target() {
+    let achievements = from_bytes::<AchievementsResource>(
+        br#"(achievements: [
+            (id: "one", condition: Kills(1)),
+            (id: "three", condition: Kills(3)),
+            (id: "boss", condition: DefeatFinalBoss),
+        ])"#,
+    )
+    .unwrap();
+    let mut progress = AchievementProgress::default();
+
+    assert_eq!(kill(&mut progress, &achievements), vec!["one"]);
+    assert!(kill(&mut progress, &achievements).is_empty());
+    assert_eq!(kill(&mut progress, &achievements), vec!["three"]);
+    assert!(kill(&mut progress, &achievements).is_empty());
+
+    assert_eq!(progress.get("one"), 1);
+    assert_eq!(progress.get("three"), 3);
+    assert_eq!(progress.get("boss"), 0);
+    assert_eq!(progress.unlocked, vec!["one", "three"]);
+}
@@ -0,0 +1,97 @@
+use bevy::prelude::Handle;
+use bevy::utils::HashMap;
+use skywalker2088::audio::{mix, SfxRequest, MAX_SFX_VOICES};
+use skywalker2088::components::audio::SoundEffectType;
+
+fn request(sound_effect_type: SoundEffectType, volume: f64) -> SfxRequest {
+    SfxRequest {
+        sound_effect_type,
+        source: Handle::default(),
+        volume,
+        panning: 0.5,
+    }
+}
+
+fn mixed(
+    requests: Vec<SfxRequest>,
+    playing: &mut HashMap<SoundEffectType, usize>,
+) -> Vec<(SoundEffectType, f64, bool)> {
+    mix(requests, playing)
+        .into_iter()
+        .map(|(request, cut_oldest)| (request.sound_effect_type, request.volume, cut_oldest))
+        .collect()
+}
+
+#[test]
+fn identical_sounds_collapse_into_the_loudest() {
+    let mut playing = HashMap::default();
+    let requests = vec![
+        request(SoundEffectType::MobHit, 0.2),
+        request(SoundEffectType::MobHit, 0.9),
+        request(SoundEffectType::MobHit, 0.5),
+        request(SoundEffectType::MobExplosion, 0.3),
+    ];
+    assert_eq!(
+        mixed(requests, &mut playing),
+        vec![
+            (SoundEffectType::MobHit, 0.9, false),
+            (SoundEffectType::MobExplosion, 0.3, false),
+        ]
+    );
+    assert_eq!(playing[&SoundEffectType::MobHit], 1);
+    assert_eq!(playing[&SoundEffectType::MobExplosion], 1);
+}
+
+#[test]
+fn high_priority_sounds_are_mixed_first() {
+    let mut playing = HashMap::default();
+    let requests = vec![
+        request(SoundEffectType::Laser, 1.0),
+        request(SoundEffectType::MobHit, 1.0),
+        request(SoundEffectType::PlayerHit, 0.1),
+    ];
+    let order: Vec<SoundEffectType> = mixed(requests, &mut playing)
+        .into_iter()
+        .map(|(sound_effect_type, _, _)| sound_effect_type)
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            SoundEffectType::PlayerHit,
+            SoundEffectType::MobHit,
+            SoundEffectType::Laser,
+        ]
+    );
+}
+
+#[test]
+fn sounds_over_their_voice_cap_are_dropped() {
+    let mut playing = HashMap::from_iter([(SoundEffectType::MobHit, 3)]);
+    let requests = vec![request(SoundEffectType::MobHit, 1.0)];
+    assert!(mixed(requests, &mut playing).is_empty());
+    assert_eq!(playing[&SoundEffectType::MobHit], 3);
+}
+
+#[test]
+fn high_priority_sounds_cut_off_their_oldest_voice() {
+    let mut playing = HashMap::from_iter([(SoundEffectType::PlayerHit, 4)]);
+    let requests = vec![request(SoundEffectType::PlayerHit, 1.0)];
+    assert_eq!(
+        mixed(requests, &mut playing),
+        vec![(SoundEffectType::PlayerHit, 1.0, true)]
+    );
+    assert_eq!(playing[&SoundEffectType::PlayerHit], 4);
+}
+
+#[test]
+fn only_high_priority_sounds_play_over_the_total_voice_cap() {
+    let mut playing = HashMap::from_iter([(SoundEffectType::RocketLaunch, MAX_SFX_VOICES)]);
+    let requests = vec![
+        request(SoundEffectType::MobExplosion, 1.0),
+        request(SoundEffectType::BulletHitBoss, 1.0),
+    ];
+    assert_eq!(
+        mixed(requests, &mut playing),
+        vec![(SoundEffectType::BulletHitBoss, 1.0, false)]
+    );
+}
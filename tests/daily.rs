@@ -0,0 +1,48 @@
+use skywalker2088::gameplay::daily::{civil_from_days, splitmix64, DailyChallenge};
+use std::collections::HashSet;
+
+#[test]
+fn days_map_to_calendar_dates() {
+    assert_eq!(civil_from_days(0), (1970, 1, 1));
+    assert_eq!(civil_from_days(-1), (1969, 12, 31));
+    assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+    assert_eq!(civil_from_days(19_723), (2024, 1, 1));
+    assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+    assert_eq!(civil_from_days(20_088), (2024, 12, 31));
+    assert_eq!(civil_from_days(47_541), (2100, 3, 1));
+}
+
+#[test]
+fn splitmix64_matches_the_reference() {
+    assert_eq!(splitmix64(0), 0xE220_A839_7B1D_CDAF);
+    assert_eq!(splitmix64(1), 0x910A_2DEC_8902_5CC1);
+}
+
+#[test]
+fn challenges_are_dated() {
+    assert_eq!(DailyChallenge::for_day(0).date, "1970-01-01");
+    assert_eq!(DailyChallenge::for_day(19_782).date, "2024-02-29");
+    assert_eq!(DailyChallenge::for_day(20_744).date, "2026-10-18");
+}
+
+#[test]
+fn same_day_same_challenge() {
+    for day in [0, 19_723, 20_744] {
+        let challenge = DailyChallenge::for_day(day);
+        assert_eq!(challenge, DailyChallenge::for_day(day));
+        assert_eq!(challenge.seed, splitmix64(day));
+    }
+    assert_ne!(
+        DailyChallenge::for_day(20_744).seed,
+        DailyChallenge::for_day(20_745).seed
+    );
+}
+
+#[test]
+fn challenges_have_two_different_modifiers() {
+    for day in 19_723..19_723 + 366 {
+        let modifiers = DailyChallenge::for_day(day).modifiers;
+        assert_eq!(modifiers.len(), 2);
+        assert_eq!(modifiers.iter().collect::<HashSet<_>>().len(), 2);
+    }
+}
@@ -0,0 +1,145 @@
+use bevy::asset::ron::de::from_bytes;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use skywalker2088::enemy::director::{
+    DirectorEnemy, DirectorEntry, DirectorTable, SpawnDirector, Tier,
+};
+
+const DIRECTOR: &[u8] = include_bytes!("../assets/data/spawn_director.ron");
+
+fn table() -> DirectorTable {
+    from_bytes::<DirectorTable>(
+        br#"(
+            starting_threat: 10.0,
+            threat_per_second: 2.0,
+            threat_growth: 1.0,
+            max_threat: 300.0,
+            wave_seconds: 5.0,
+            max_elites: 2,
+            max_bosses: 1,
+            enemies: [
+                (enemy: Drone, cost: 1.0, unlock_seconds: 0.0, weight: 20, tier: Regular),
+                (enemy: Fighter, cost: 4.0, unlock_seconds: 60.0, weight: 6, tier: Regular),
+                (enemy: DroneBoss, cost: 15.0, unlock_seconds: 150.0, weight: 2, tier: Elite),
+                (enemy: Mothership, cost: 60.0, unlock_seconds: 300.0, weight: 1, tier: Boss),
+            ],
+        )"#,
+    )
+    .unwrap()
+}
+
+fn director(threat: f32) -> SpawnDirector {
+    SpawnDirector {
+        threat,
+        ..Default::default()
+    }
+}
+
+/// Every enemy the director picks over many rolls
+fn picks(table: &DirectorTable, seconds: f32, director: &SpawnDirector) -> Vec<DirectorEnemy> {
+    let mut rng = StdRng::seed_from_u64(2088);
+    let mut picked: Vec<DirectorEnemy> = (0..500)
+        .filter_map(|_| table.pick(seconds, director, &mut rng))
+        .map(|entry| entry.enemy)
+        .collect();
+    picked.sort_by_key(|enemy| *enemy as u8);
+    picked.dedup();
+    picked
+}
+
+fn entry(table: &DirectorTable, enemy: DirectorEnemy) -> DirectorEntry {
+    table
+        .enemies
+        .iter()
+        .find(|entry| entry.enemy == enemy)
+        .unwrap()
+        .clone()
+}
+
+#[test]
+fn director_table_loads() {
+    let table = from_bytes::<DirectorTable>(DIRECTOR).unwrap();
+    assert!(!table.enemies.is_empty());
+}
+
+#[test]
+fn enemies_unlock_over_time() {
+    let table = table();
+    let rich = director(1000.0);
+    assert_eq!(picks(&table, 0.0, &rich), [DirectorEnemy::Drone]);
+    assert_eq!(
+        picks(&table, 60.0, &rich),
+        [DirectorEnemy::Drone, DirectorEnemy::Fighter]
+    );
+    assert_eq!(
+        picks(&table, 300.0, &rich),
+        [
+            DirectorEnemy::Drone,
+            DirectorEnemy::Fighter,
+            DirectorEnemy::DroneBoss,
+            DirectorEnemy::Mothership,
+        ]
+    );
+}
+
+#[test]
+fn only_affordable_enemies_are_picked() {
+    let table = table();
+    assert_eq!(picks(&table, 600.0, &director(3.0)), [DirectorEnemy::Drone]);
+    assert_eq!(
+        picks(&table, 600.0, &director(15.0)),
+        [
+            DirectorEnemy::Drone,
+            DirectorEnemy::Fighter,
+            DirectorEnemy::DroneBoss,
+        ]
+    );
+    assert!(picks(&table, 600.0, &director(0.5)).is_empty());
+}
+
+#[test]
+fn elites_and_bosses_are_capped() {
+    let table = table();
+    let capped = SpawnDirector {
+        threat: 1000.0,
+        elites: 2,
+        bosses: 1,
+        ..Default::default()
+    };
+    assert_eq!(
+        picks(&table, 600.0, &capped),
+        [DirectorEnemy::Drone, DirectorEnemy::Fighter]
+    );
+}
+
+#[test]
+fn buying_spends_threat_and_counts_tiers() {
+    let table = table();
+    let mut director = director(100.0);
+
+    director.buy(&entry(&table, DirectorEnemy::Drone));
+    director.buy(&entry(&table, DirectorEnemy::DroneBoss));
+    director.buy(&entry(&table, DirectorEnemy::Mothership));
+
+    assert_eq!(director.threat, 24.0);
+    assert_eq!(director.last_wave, 76.0);
+    assert_eq!(director.elites, 1);
+    assert_eq!(director.bosses, 1);
+    assert_eq!(entry(&table, DirectorEnemy::Mothership).tier, Tier::Boss);
+}
+
+#[test]
+fn elites_and_bosses_stand_out() {
+    let table = table();
+    assert!(!table.is_boss_or_elite(&DirectorEnemy::Drone.mob_type()));
+    assert!(table.is_boss_or_elite(&DirectorEnemy::DroneBoss.mob_type()));
+    assert!(table.is_boss_or_elite(&DirectorEnemy::Mothership.mob_type()));
+}
+
+#[test]
+fn only_bosses_count_as_bosses() {
+    let table = table();
+    assert!(!table.is_boss(&DirectorEnemy::Drone.mob_type()));
+    assert!(!table.is_boss(&DirectorEnemy::DroneBoss.mob_type()));
+    assert!(table.is_boss(&DirectorEnemy::Mothership.mob_type()));
+}
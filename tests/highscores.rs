@@ -0,0 +1,54 @@
+use skywalker2088::gameplay::difficulty::Difficulty;
+use skywalker2088::gameplay::highscore::{HighScore, HighScores, MAX_HIGH_SCORES};
+use skywalker2088::gameplay::mode::GameMode;
+
+fn run(score: u32, mode: GameMode) -> HighScore {
+    HighScore {
+        name: format!("{:?} {}", mode, score),
+        score,
+        seconds: 60,
+        level: 1,
+        weapons: Vec::new(),
+        difficulty: Difficulty::Normal,
+        mode,
+    }
+}
+
+fn scores(high_scores: &HighScores, mode: GameMode) -> Vec<u32> {
+    high_scores
+        .table(mode)
+        .iter()
+        .map(|high_score| high_score.score)
+        .collect()
+}
+
+#[test]
+fn insert_ranks_within_the_mode() {
+    let mut high_scores = HighScores::default();
+    assert_eq!(high_scores.insert(run(100, GameMode::Classic)), Some(0));
+    assert_eq!(high_scores.insert(run(500, GameMode::Endless)), Some(0));
+    assert_eq!(high_scores.insert(run(300, GameMode::Classic)), Some(0));
+    assert_eq!(high_scores.insert(run(200, GameMode::Classic)), Some(1));
+
+    assert_eq!(scores(&high_scores, GameMode::Classic), [300, 200, 100]);
+    assert_eq!(scores(&high_scores, GameMode::Endless), [500]);
+}
+
+#[test]
+fn full_table_only_drops_runs_of_the_same_mode() {
+    let mut high_scores = HighScores::default();
+    for score in 1..=MAX_HIGH_SCORES as u32 {
+        high_scores.insert(run(score * 10, GameMode::Classic));
+    }
+    high_scores.insert(run(5, GameMode::BossRush));
+    assert!(!high_scores.qualifies(GameMode::Classic, 10));
+    assert_eq!(high_scores.insert(run(5, GameMode::Classic)), None);
+
+    assert_eq!(high_scores.insert(run(1000, GameMode::Classic)), Some(0));
+    let classic = scores(&high_scores, GameMode::Classic);
+    assert_eq!(classic.len(), MAX_HIGH_SCORES);
+    assert_eq!(classic.first(), Some(&1000));
+    assert_eq!(classic.last(), Some(&20));
+    // 其他模式的记录不受影响
+    assert_eq!(scores(&high_scores, GameMode::BossRush), [5]);
+}
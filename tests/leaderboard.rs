@@ -0,0 +1,132 @@
+use skywalker2088::gameplay::mode::GameMode;
+use skywalker2088::leaderboard::{
+    Leaderboard, LeaderboardClient, LocalLeaderboard, OfflineLeaderboard, Rankings, RunSubmission,
+};
+use std::sync::mpsc;
+
+fn run(name: &str, score: u32) -> RunSubmission {
+    mode_run(name, score, GameMode::Classic)
+}
+
+fn mode_run(name: &str, score: u32, mode: GameMode) -> RunSubmission {
+    RunSubmission {
+        name: name.to_string(),
+        score,
+        mode,
+        seed: None,
+        seconds: 90,
+        build: "test".to_string(),
+    }
+}
+
+#[test]
+fn local_server_ranks_submitted_runs() {
+    let server = LocalLeaderboard::default();
+    let mut leaderboard = Leaderboard::new(Box::new(server.clone()));
+    leaderboard.submit(run("Low", 10));
+    leaderboard.submit(run("High", 300));
+    leaderboard.submit(run("Mid", 120));
+    assert_eq!(server.runs().len(), 3);
+
+    leaderboard.refresh(GameMode::Classic);
+    assert_eq!(leaderboard.rankings, Rankings::Loading);
+    let Some(Rankings::Global(entries)) = leaderboard.poll() else {
+        panic!("expected global rankings");
+    };
+    let names: Vec<_> = entries.iter().map(|entry| entry.name.as_str()).collect();
+    assert_eq!(names, ["High", "Mid", "Low"]);
+    assert_eq!(entries[0].rank, 1);
+    assert!(leaderboard.poll().is_none());
+}
+
+#[test]
+fn rankings_are_kept_per_mode() {
+    let server = LocalLeaderboard::default();
+    let mut leaderboard = Leaderboard::new(Box::new(server));
+    leaderboard.submit(mode_run("Classic", 100, GameMode::Classic));
+    leaderboard.submit(mode_run("Endless", 900, GameMode::Endless));
+    leaderboard.submit(mode_run("Rush", 500, GameMode::BossRush));
+
+    leaderboard.refresh(GameMode::Classic);
+    let Some(Rankings::Global(entries)) = leaderboard.poll() else {
+        panic!("expected global rankings");
+    };
+    let names: Vec<_> = entries.iter().map(|entry| entry.name.as_str()).collect();
+    assert_eq!(names, ["Classic"]);
+
+    leaderboard.refresh(GameMode::Daily);
+    assert_eq!(leaderboard.poll(), Some(Rankings::Global(Vec::new())));
+}
+
+#[test]
+fn offline_client_falls_back_to_local_scores() {
+    let mut leaderboard = Leaderboard::new(Box::new(OfflineLeaderboard));
+    leaderboard.refresh(GameMode::Classic);
+    assert_eq!(leaderboard.poll(), Some(Rankings::Unavailable));
+
+    let (sender, receiver) = mpsc::channel();
+    OfflineLeaderboard.submit(
+        run("Nobody", 1),
+        Box::new(move |result| sender.send(result.is_ok()).unwrap()),
+    );
+    assert!(!receiver.recv().unwrap());
+}
+
+#[cfg(feature = "online")]
+#[test]
+fn http_client_posts_runs_as_json() {
+    use skywalker2088::leaderboard::HttpLeaderboard;
+
+    let client = HttpLeaderboard::new("https://scores.example");
+    let mut submission = mode_run("Ace", 300, GameMode::Daily);
+    submission.seed = Some(42);
+    let request = client.submit_request(&submission).unwrap();
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.url, "https://scores.example/runs");
+    assert_eq!(
+        request.headers.get("Content-Type"),
+        Some("application/json")
+    );
+
+    let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "name": "Ace",
+            "score": 300,
+            "mode": "Daily",
+            "seed": 42,
+            "seconds": 90,
+            "build": "test",
+        })
+    );
+}
+
+#[cfg(feature = "online")]
+#[test]
+fn http_client_reads_json_rankings() {
+    use skywalker2088::leaderboard::{HttpLeaderboard, LeaderboardEntry, LeaderboardError};
+
+    let client = HttpLeaderboard::new("https://scores.example");
+    let request = client.fetch_request(GameMode::BossRush, 10);
+    assert_eq!(request.method, "GET");
+    assert_eq!(
+        request.url,
+        "https://scores.example/rankings?mode=BossRush&limit=10"
+    );
+
+    let body = br#"[{"rank":1,"name":"Ace","score":300,"seconds":90}]"#;
+    assert_eq!(
+        HttpLeaderboard::parse_rankings(body),
+        Ok(vec![LeaderboardEntry {
+            rank: 1,
+            name: "Ace".to_string(),
+            score: 300,
+            seconds: 90,
+        }])
+    );
+    assert!(matches!(
+        HttpLeaderboard::parse_rankings(b"<html>502</html>"),
+        Err(LeaderboardError::InvalidResponse(_))
+    ));
+}
@@ -0,0 +1,94 @@
+use skywalker2088::theme::language::Localize;
+
+const TRANSLATIONS: &str = include_str!("../assets/configs/duolingo.csv");
+
+#[test]
+fn every_language_column_is_complete() {
+    let localize = Localize::from_data(TRANSLATIONS);
+    let empty = localize.empty_cells();
+    assert!(
+        empty.is_empty(),
+        "untranslated cells (language, keyword): {:?}",
+        empty
+    );
+}
+
+#[test]
+fn missing_keywords_are_reported() {
+    let localize = Localize::from_data(TRANSLATIONS);
+    assert_eq!(localize.get("turret.auto_cannon.desc.missing"), "");
+    assert_eq!(
+        localize.missing_keywords(),
+        vec!["turret.auto_cannon.desc.missing".to_string()]
+    );
+}
+
+const SAMPLE: &str = "Keyword,Comment,English,Chinese
+greeting,,Hello {name}! Ready {name}?,你好 {name}！准备好了吗？
+wave,,Wave {wave} of {total},第 {wave} 波，共 {total} 波
+enemies.one,,{count} enemy left,
+enemies.other,,{count} enemies left,剩余 {count} 个敌人
+score,,{count} points by {name},{name} 得分 {count}
+untranslated,,Only in English,";
+
+#[test]
+fn format_substitutes_every_placeholder() {
+    let localize = Localize::from_data(SAMPLE);
+    assert_eq!(
+        localize.format("greeting", &[("name", &"Ace")]),
+        "Hello Ace! Ready Ace?"
+    );
+    assert_eq!(
+        localize.format("wave", &[("total", &10), ("wave", &3)]),
+        "Wave 3 of 10"
+    );
+
+    let chinese = Localize::from_data(SAMPLE).with_default_language("Chinese");
+    assert_eq!(
+        chinese.format("wave", &[("wave", &3), ("total", &10)]),
+        "第 3 波，共 10 波"
+    );
+}
+
+#[test]
+fn placeholders_without_arguments_are_kept() {
+    let localize = Localize::from_data(SAMPLE);
+    assert_eq!(localize.format("wave", &[]), "Wave {wave} of {total}");
+    assert_eq!(
+        localize.format("wave", &[("wave", &3), ("level", &2)]),
+        "Wave 3 of {total}"
+    );
+}
+
+#[test]
+fn plural_picks_the_form_of_the_language() {
+    let english = Localize::from_data(SAMPLE);
+    assert_eq!(english.plural("enemies", 1, &[]), "1 enemy left");
+    assert_eq!(english.plural("enemies", 0, &[]), "0 enemies left");
+    assert_eq!(english.plural("enemies", 5, &[]), "5 enemies left");
+
+    // 中文没有单复数之分，总是使用 other
+    let chinese = Localize::from_data(SAMPLE).with_default_language("Chinese");
+    assert_eq!(chinese.plural("enemies", 1, &[]), "剩余 1 个敌人");
+}
+
+#[test]
+fn plural_without_forms_uses_the_bare_keyword() {
+    let localize = Localize::from_data(SAMPLE);
+    assert_eq!(
+        localize.plural("score", 1, &[("name", &"Ace")]),
+        "1 points by Ace"
+    );
+    assert!(localize.missing_keywords().is_empty());
+}
+
+#[test]
+fn empty_cells_fall_back_to_english() {
+    let chinese = Localize::from_data(SAMPLE).with_default_language("Chinese");
+    assert_eq!(chinese.get("untranslated"), "Only in English");
+    assert_eq!(
+        chinese.format("greeting", &[("name", &"Ace")]),
+        "你好 Ace！准备好了吗？"
+    );
+    assert!(chinese.missing_keywords().is_empty());
+}
@@ -0,0 +1,169 @@
+use bevy::math::Vec2;
+use skywalker2088::components::weapon::{
+    ArcPatternData, FireMode, RandomPatternData, SpreadPattern,
+};
+use skywalker2088::ship::turret::{DoesDamage, FireRate, TurretBundle, TurretClass};
+use std::f32::consts::{FRAC_PI_2, PI};
+use std::time::Duration;
+
+fn arc() -> SpreadPattern {
+    SpreadPattern::Arc(ArcPatternData {
+        spread_weights: Vec2::ONE,
+        max_spread: PI / 6.0,
+        projectile_gap: PI / 12.0,
+    })
+}
+
+fn angles(velocities: &[Vec2]) -> Vec<f32> {
+    velocities
+        .iter()
+        .map(|velocity| velocity.to_angle())
+        .collect()
+}
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1e-4,
+        "expected {expected}, got {actual}"
+    );
+}
+
+fn secs(seconds: f32) -> Duration {
+    Duration::from_secs_f32(seconds)
+}
+
+#[test]
+fn single_shot_flies_straight() {
+    let velocities = arc().velocities(FRAC_PI_2, 10.0, 1);
+    assert_eq!(velocities.len(), 1);
+    assert_close(velocities[0].x, 0.0);
+    assert_close(velocities[0].y, 10.0);
+}
+
+#[test]
+fn arc_spreads_evenly_around_the_firing_angle() {
+    let velocities = arc().velocities(FRAC_PI_2, 10.0, 3);
+    let angles = angles(&velocities);
+    assert_eq!(angles.len(), 3);
+    assert_close(angles[0], FRAC_PI_2 - PI / 12.0);
+    assert_close(angles[1], FRAC_PI_2);
+    assert_close(angles[2], FRAC_PI_2 + PI / 12.0);
+    for velocity in velocities {
+        assert_close(velocity.length(), 10.0);
+    }
+}
+
+#[test]
+fn arc_never_grows_wider_than_the_max_spread() {
+    let angles = angles(&arc().velocities(FRAC_PI_2, 10.0, 9));
+    assert_eq!(angles.len(), 9);
+    assert_close(angles[8] - angles[0], PI / 6.0);
+    for pair in angles.windows(2) {
+        assert_close(pair[1] - pair[0], PI / 48.0);
+    }
+}
+
+#[test]
+fn patterns_are_rotated_towards_the_aim() {
+    let velocities = arc().velocities_towards(Vec2::X, 10.0, 3);
+    let angles = angles(&velocities);
+    assert_close(angles[0], -PI / 12.0);
+    assert_close(angles[1], 0.0);
+    assert_close(angles[2], PI / 12.0);
+}
+
+#[test]
+fn random_spread_stays_within_its_ranges() {
+    let pattern = SpreadPattern::Random(RandomPatternData {
+        speed_range: 0.5..1.5,
+        angle_range: 0.75..1.25,
+    });
+    let velocities = pattern.velocities(FRAC_PI_2, 10.0, 200);
+    assert_eq!(velocities.len(), 200);
+    for velocity in velocities {
+        let angle = velocity.to_angle();
+        assert!((FRAC_PI_2 * 0.75 - 1e-4..FRAC_PI_2 * 1.25 + 1e-4).contains(&angle));
+        assert!((5.0 - 1e-3..15.0 + 1e-3).contains(&velocity.length()));
+    }
+}
+
+#[test]
+fn empty_random_range_is_constant() {
+    let pattern = SpreadPattern::Random(RandomPatternData {
+        speed_range: 1.0..1.0,
+        angle_range: 1.0..1.0,
+    });
+    for velocity in pattern.velocities(FRAC_PI_2, 10.0, 5) {
+        assert_close(velocity.x, 0.0);
+        assert_close(velocity.y, 10.0);
+    }
+}
+
+#[test]
+fn automatic_turrets_fire_whenever_they_reload() {
+    let mut fire_rate = FireRate::from_rate_in_seconds(2.0);
+    assert!(!fire_rate.fire(secs(0.25), FireMode::Automatic, false));
+    assert!(fire_rate.fire(secs(0.25), FireMode::Automatic, false));
+    assert!(!fire_rate.fire(secs(0.25), FireMode::Automatic, false));
+    assert!(fire_rate.fire(secs(0.25), FireMode::Automatic, false));
+}
+
+#[test]
+fn manual_turrets_hold_their_shot_until_triggered() {
+    let mut fire_rate = FireRate::from_rate_in_seconds(2.0);
+    assert!(!fire_rate.fire(secs(0.25), FireMode::Manual, true));
+    assert!(!fire_rate.fire(secs(0.25), FireMode::Manual, false));
+    assert!(!fire_rate.fire(secs(2.0), FireMode::Manual, false));
+    assert!(fire_rate.fire(Duration::ZERO, FireMode::Manual, true));
+    // 一次只有一发，开火后要重新装填
+    assert!(!fire_rate.fire(secs(0.25), FireMode::Manual, true));
+    assert!(fire_rate.fire(secs(0.25), FireMode::Manual, true));
+}
+
+#[test]
+fn reset_drops_a_held_shot() {
+    let mut fire_rate = FireRate::from_rate_in_seconds(2.0);
+    assert!(!fire_rate.fire(secs(0.5), FireMode::Manual, false));
+    fire_rate.reset();
+    assert!(!fire_rate.fire(Duration::ZERO, FireMode::Manual, true));
+}
+
+#[test]
+fn only_the_pierce_laser_waits_for_the_trigger() {
+    for class in [
+        TurretClass::AutoCannon,
+        TurretClass::BlastLaser,
+        TurretClass::RocketLauncher,
+        TurretClass::MineLauncher,
+        TurretClass::ShrapnelCannon,
+        TurretClass::ChainLaser,
+        TurretClass::PierceLaser,
+        TurretClass::Emp,
+    ] {
+        let expected = if class == TurretClass::PierceLaser {
+            FireMode::Manual
+        } else {
+            FireMode::Automatic
+        };
+        assert_eq!(TurretBundle::from_class(&class).fire_mode, expected);
+    }
+}
+
+#[test]
+fn damage_multiplier_keeps_fractions_on_average() {
+    let easy = DoesDamage {
+        multiplier: 0.5,
+        ..DoesDamage::from_amount(1)
+    };
+    assert_eq!(easy.scaled(1, 0.25), 1);
+    assert_eq!(easy.scaled(1, 0.75), 0);
+    assert_eq!(easy.scaled(4, 0.99), 2);
+
+    let hard = DoesDamage {
+        multiplier: 1.5,
+        ..DoesDamage::from_amount(1)
+    };
+    assert_eq!(hard.scaled(1, 0.25), 2);
+    assert_eq!(hard.scaled(1, 0.75), 1);
+    assert_eq!(DoesDamage::from_amount(3).scaled(3, 0.0), 3);
+}